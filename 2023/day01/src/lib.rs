@@ -1,7 +1,24 @@
-fn main() {
-    let file_content = include_str!("../data.txt");
-    println!("Part 1: {}", part1(file_content));
-    println!("Part 2: {}", part2(file_content));
+use std::fmt::Display;
+use utils::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// The calibration document, which both parts scan character by
+    /// character.
+    type Input = String;
+
+    fn parse(data: &str) -> Self::Input {
+        data.to_string()
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
 }
 
 fn part1(data: &str) -> usize {
@@ -11,20 +28,16 @@ fn part1(data: &str) -> usize {
             let mut first_num = None;
             let mut last_num = None;
 
-            let mut chars = line.chars();
-            while let Some(char) = chars.next() {
-                match char {
-                    '0'..='9' => {
-                        if let None = first_num {
-                            first_num = Some(char);
-                        }
-                        last_num = Some(char);
+            for char in line.chars() {
+                if let '0'..='9' = char {
+                    if first_num.is_none() {
+                        first_num = Some(char);
                     }
-                    _ => {}
+                    last_num = Some(char);
                 }
             }
 
-            return first_num
+            first_num
                 .expect("line to have at least 1 digit")
                 .to_digit(10)
                 .unwrap() as usize
@@ -32,7 +45,7 @@ fn part1(data: &str) -> usize {
                 + last_num
                     .expect("line to have at least 1 digit")
                     .to_digit(10)
-                    .unwrap() as usize;
+                    .unwrap() as usize
         })
         .fold(0, |mut acc, item| {
             acc += item;
@@ -48,7 +61,7 @@ fn part2(data: &str) -> usize {
             return false;
         }
 
-        return &data[i..(i + str.len())].to_lowercase() == str;
+        data[i..(i + str.len())].to_lowercase() == str
     };
 
     let mut result: usize = 0;
@@ -57,7 +70,7 @@ fn part2(data: &str) -> usize {
 
     for (i, char) in data.char_indices() {
         let mut set_nums = |value: char| {
-            if let None = first_num {
+            if first_num.is_none() {
                 first_num = Some(value);
             }
             last_num = Some(value);
@@ -67,10 +80,8 @@ fn part2(data: &str) -> usize {
             '0'..='9' => {
                 set_nums(char);
             }
-            'o' | 'O' => {
-                if match_at(i, "one") {
-                    set_nums('1');
-                }
+            'o' | 'O' if match_at(i, "one") => {
+                set_nums('1');
             }
             't' | 'T' => {
                 if match_at(i, "two") {
@@ -93,15 +104,11 @@ fn part2(data: &str) -> usize {
                     set_nums('7');
                 }
             }
-            'e' | 'E' => {
-                if match_at(i, "eight") {
-                    set_nums('8');
-                }
+            'e' | 'E' if match_at(i, "eight") => {
+                set_nums('8');
             }
-            'n' | 'N' => {
-                if match_at(i, "nine") {
-                    set_nums('9');
-                }
+            'n' | 'N' if match_at(i, "nine") => {
+                set_nums('9');
             }
             '\n' => {
                 result += first_num
@@ -126,15 +133,19 @@ fn part2(data: &str) -> usize {
 
 #[cfg(test)]
 mod tests {
+    use utils::Solution;
+
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn cases() {
+        let input = super::Day01::parse(&utils::require_input!(2023, 1));
         assert_eq!(
             format!("{}", 54953),
-            format!("{}", super::part1(include_str!("../data.txt")))
+            format!("{}", super::Day01::part1(&input))
         );
         assert_eq!(
             format!("{}", 53868),
-            format!("{}", super::part2(include_str!("../data.txt")))
+            format!("{}", super::Day01::part2(&input))
         );
     }
 }
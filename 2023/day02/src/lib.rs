@@ -0,0 +1,131 @@
+use std::fmt::Display;
+use tokenizer::{combinators::*, ParseError, Parser};
+use utils::Solution;
+
+/// The cubes the elf says are in the bag for part 1.
+pub const BAG_CONTENTS: Round = Round {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(data: &str) -> Self::Input {
+        parse_input(data).unwrap_or_else(|err| panic!("Failed to parse input. Message: {}", err))
+    }
+
+    fn part1(games: &Self::Input) -> impl Display {
+        possible_games(games, &BAG_CONTENTS)
+            .map(|game| game.number)
+            .sum::<isize>()
+    }
+
+    fn part2(games: &Self::Input) -> impl Display {
+        let mut result = 0;
+        for game in games {
+            let maxes = game.rounds.iter().fold(
+                Round {
+                    red: 0,
+                    blue: 0,
+                    green: 0,
+                },
+                |mut acc, round| {
+                    if round.red > acc.red {
+                        acc.red = round.red;
+                    }
+                    if round.blue > acc.blue {
+                        acc.blue = round.blue;
+                    }
+                    if round.green > acc.green {
+                        acc.green = round.green;
+                    }
+                    acc
+                },
+            );
+
+            result += maxes.red * maxes.blue * maxes.green;
+        }
+
+        result
+    }
+}
+
+/// The games that could have been played with `bag_contents` in the bag.
+pub fn possible_games<'g>(
+    games: &'g [Game],
+    bag_contents: &'g Round,
+) -> impl Iterator<Item = &'g Game> {
+    games.iter().filter(|game| {
+        game.rounds.iter().all(|round| {
+            round.red <= bag_contents.red
+                && round.blue <= bag_contents.blue
+                && round.green <= bag_contents.green
+        })
+    })
+}
+
+fn parse_input(data: &str) -> Result<Vec<Game>, ParseError> {
+    lines(parse_game)(&mut Parser::new(data))
+}
+
+fn parse_game(parser: &mut Parser) -> Result<Game, ParseError> {
+    let cubes = tuple((int, spaces, alt((tag("red"), tag("blue"), tag("green")))));
+    let round = map(sep_by1(cubes, tag(", ")), |cubes| {
+        cubes.into_iter().fold(
+            Round {
+                red: 0,
+                blue: 0,
+                green: 0,
+            },
+            |mut round, (count, _, color)| {
+                match color {
+                    "red" => round.red = count,
+                    "blue" => round.blue = count,
+                    _ => round.green = count,
+                }
+                round
+            },
+        )
+    });
+
+    let mut game = map(
+        tuple((tag("Game "), int, tag(": "), sep_by1(round, tag("; ")))),
+        |(_, number, _, rounds)| Game { number, rounds },
+    );
+
+    game(parser)
+}
+
+pub struct Game {
+    number: isize,
+    rounds: Vec<Round>,
+}
+
+pub struct Round {
+    red: isize,
+    blue: isize,
+    green: isize,
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::Solution;
+
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn cases() {
+        let games = super::Day02::parse(&utils::require_input!(2023, 2));
+        assert_eq!(
+            format!("{}", 2512),
+            format!("{}", super::Day02::part1(&games))
+        );
+        assert_eq!(
+            format!("{}", 67335),
+            format!("{}", super::Day02::part2(&games))
+        );
+    }
+}
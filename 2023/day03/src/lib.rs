@@ -0,0 +1,126 @@
+use std::{collections::HashMap, fmt::Display};
+use tokenizer::{SourceMap, Span};
+use utils::{Grid, Run, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<char>;
+
+    fn parse(data: &str) -> Self::Input {
+        parse_schematic(data)
+    }
+
+    fn part1(schematic: &Self::Input) -> impl Display {
+        schematic
+            .runs(|ch| ch.is_ascii_digit())
+            .into_iter()
+            .filter(|run| schematic.around(run).any(|cell| is_symbol(schematic[cell])))
+            .map(|run| part_number(schematic, &run))
+            .sum::<usize>()
+    }
+
+    fn part2(schematic: &Self::Input) -> impl Display {
+        let mut parts_by_gear = HashMap::new();
+
+        for run in schematic.runs(|ch| ch.is_ascii_digit()) {
+            let part = part_number(schematic, &run);
+            for gear in schematic
+                .around(&run)
+                .filter(|cell| schematic[*cell] == '*')
+            {
+                parts_by_gear.entry(gear).or_insert(Vec::new()).push(part);
+            }
+        }
+
+        parts_by_gear
+            .into_values()
+            .filter(|parts| parts.len() == 2)
+            .map(|parts| parts[0] * parts[1])
+            .sum::<usize>()
+    }
+}
+
+fn parse_schematic(data: &str) -> Grid<char> {
+    let unrecognized = data
+        .char_indices()
+        .find(|(_, ch)| !matches!(ch, '.' | '0'..='9' | '\n' | '\r') && !is_symbol(*ch));
+
+    if let Some((i, ch)) = unrecognized {
+        panic!(
+            "Unrecognized character\n{}",
+            SourceMap::new(data).render(Span::new(i, i + ch.len_utf8()))
+        );
+    }
+
+    Grid::parse_padded(data, '.', |ch| ch)
+}
+
+fn part_number(schematic: &Grid<char>, run: &Run) -> usize {
+    run.positions()
+        .map(|cell| schematic[cell])
+        .collect::<String>()
+        .parse()
+        .expect("run of digits to be a valid number")
+}
+
+fn is_symbol(ch: char) -> bool {
+    matches!(
+        ch,
+        '@' | '#'
+            | '$'
+            | '%'
+            | '&'
+            | '*'
+            | '-'
+            | '_'
+            | '+'
+            | '='
+            | '/'
+            | '>'
+            | '<'
+            | ','
+            | '~'
+            | '`'
+            | '\''
+            | '"'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day03;
+    use utils::Solution;
+
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn real_question() {
+        let schematic = Day03::parse(&utils::require_input!(2023, 3));
+        assert_eq!(
+            format!("{}", 544664),
+            format!("{}", Day03::part1(&schematic))
+        );
+        assert_eq!(
+            format!("{}", 84495585),
+            format!("{}", Day03::part2(&schematic))
+        );
+    }
+
+    #[test]
+    fn part2_side_by_side() {
+        let side_by_side = "120*80";
+        assert_eq!(
+            format!("{}", 120 * 80),
+            format!("{}", Day03::part2(&Day03::parse(side_by_side)))
+        );
+    }
+
+    #[test]
+    fn part2_above() {
+        let above = "120\n*..\n80";
+        assert_eq!(
+            format!("{}", 120 * 80),
+            format!("{}", Day03::part2(&Day03::parse(above)))
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::{collections::VecDeque, fmt::Display};
+use tokenizer::{FromPuzzleInput, ParseError};
+use utils::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(data: &str) -> Self::Input {
+        parse_input(data).unwrap_or_else(|err| panic!("Failed to parse input. Message: {}", err))
+    }
+
+    fn part1(cards: &Self::Input) -> impl Display {
+        let mut total = 0;
+        for card in cards {
+            let mut card_val = 0;
+            for actual_num in card.actual_nums.iter() {
+                if card.winning_nums.contains(actual_num) {
+                    if card_val == 0 {
+                        card_val = 1;
+                    } else {
+                        card_val *= 2;
+                    }
+                }
+            }
+            total += card_val
+        }
+
+        total
+    }
+
+    fn part2(cards: &Self::Input) -> impl Display {
+        let mut queue_of_stacks = VecDeque::new();
+        queue_of_stacks.extend(cards.iter().map(|card| (1, card)));
+
+        for index in 0..queue_of_stacks.len() {
+            let card_tuple = queue_of_stacks.get(index).unwrap();
+            let count = card_tuple.0;
+            let card = card_tuple.1;
+
+            let mut card_val = 0;
+            for actual_num in card.actual_nums.iter() {
+                if card.winning_nums.contains(actual_num) {
+                    card_val += 1;
+                }
+            }
+
+            for i in 1..card_val + 1 {
+                queue_of_stacks.get_mut(index + i).unwrap().0 += count;
+            }
+        }
+
+        queue_of_stacks
+            .into_iter()
+            .map(|stack| stack.0)
+            .sum::<i32>()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Card>, ParseError> {
+    tokenizer::parse_lines(input)
+}
+
+#[derive(Debug, Clone, FromPuzzleInput)]
+#[puzzle("Card {num}: {winning_nums: sep=' '} | {actual_nums: sep=' '}")]
+pub struct Card {
+    pub num: isize,
+    pub actual_nums: Vec<isize>,
+    pub winning_nums: Vec<isize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day04;
+    use utils::Solution;
+
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn part1_actual() {
+        let cards = Day04::parse(&utils::require_input!(2023, 4));
+        assert_eq!(format!("{}", 21568), format!("{}", Day04::part1(&cards)));
+    }
+
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn part2_actual() {
+        let cards = Day04::parse(&utils::require_input!(2023, 4));
+        assert_eq!(format!("{}", 11827296), format!("{}", Day04::part2(&cards)));
+    }
+}
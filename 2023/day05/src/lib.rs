@@ -0,0 +1,387 @@
+use std::fmt::Display;
+use tokenizer::{combinators::*, Expected, ParseError, Parser, Span};
+use utils::{interval::Overlap, IntervalSet, RangeMap, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(data: &str) -> Self::Input {
+        Almanac::parse(data).unwrap_or_else(|err| panic!("Failed to parse input. Message: {}", err))
+    }
+
+    fn part1(almanac: &Self::Input) -> impl Display {
+        part1(almanac)
+    }
+
+    fn part2(almanac: &Self::Input) -> impl Display {
+        part2(almanac)
+    }
+}
+
+fn part1(almanac: &Almanac) -> impl Display {
+    let seed_to_location = seed_to_location(almanac);
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| seed_to_location.get(*seed))
+        .min()
+        .unwrap_or(0)
+}
+
+fn part2(almanac: &Almanac) -> impl Display {
+    let seeds = almanac
+        .seeds
+        .chunks(2)
+        .map(|range| range[0]..range[0] + range[1])
+        .collect::<IntervalSet>();
+
+    seed_to_location(almanac).apply(&seeds).min().unwrap_or(0)
+}
+
+fn seed_to_location(almanac: &Almanac) -> RangeMap {
+    almanac
+        .path("seed", "location")
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// The puzzle input: the seeds, then maps that lead from seeds through each
+/// category in turn.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    /// Each map's source is the previous one's destination, and the first
+    /// one's is "seed".
+    pub maps: Vec<CategoryMap>,
+}
+
+/// One section of the almanac, such as `seed-to-soil map:`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CategoryMap {
+    pub source: String,
+    pub destination: String,
+    pub map: RangeMap,
+}
+
+#[derive(Debug)]
+pub enum AlmanacError {
+    /// A line didn't parse. Lines are numbered from 1.
+    Parse { line: usize, error: ParseError },
+    /// Two lines of the same map cover the same source numbers.
+    Overlap { line: usize, overlap: Overlap },
+    /// A map doesn't start where the previous one ended.
+    BrokenChain { expected: String, found: String },
+    /// A category appears in more than one place along the chain.
+    RepeatedCategory(String),
+    /// There was nothing but blank lines.
+    Empty,
+}
+
+impl Display for AlmanacError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // the error already says which line it's on
+            Self::Parse { error, .. } => write!(f, "{}", error),
+            Self::Overlap { line, overlap } => write!(f, "line {}: {}", line, overlap),
+            Self::BrokenChain { expected, found } => write!(
+                f,
+                "expected a map from {:?}, found one from {:?}",
+                expected, found
+            ),
+            Self::RepeatedCategory(category) => {
+                write!(f, "category {:?} appears more than once", category)
+            }
+            Self::Empty => write!(f, "the almanac is empty"),
+        }
+    }
+}
+
+impl std::error::Error for AlmanacError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PathError {
+    UnknownCategory(String),
+    /// The path runs backwards through a map that sends two numbers to the
+    /// same one.
+    NotInvertible {
+        source: String,
+        destination: String,
+    },
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownCategory(category) => write!(f, "no category named {:?}", category),
+            Self::NotInvertible {
+                source,
+                destination,
+            } => write!(
+                f,
+                "the {}-to-{} map can't be run backwards",
+                source, destination
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+impl Almanac {
+    /// Parses the almanac, ignoring line endings, surrounding whitespace and
+    /// extra blank lines.
+    pub fn parse(data: &str) -> Result<Self, AlmanacError> {
+        // trimmed lines, grouped into blank-line separated sections
+        let mut sections = Vec::<Vec<Line>>::new();
+        let mut in_section = false;
+        let mut offset = 0;
+        for (i, raw) in data.split_inclusive('\n').enumerate() {
+            let line = Line {
+                number: i + 1,
+                offset: offset + raw.len() - raw.trim_start().len(),
+                text: raw.trim(),
+            };
+            offset += raw.len();
+            if line.text.is_empty() {
+                in_section = false;
+                continue;
+            }
+            if !in_section {
+                sections.push(Vec::new());
+                in_section = true;
+            }
+            sections.last_mut().unwrap().push(line);
+        }
+
+        let mut sections = sections.into_iter();
+        let seeds = match sections.next().as_deref() {
+            Some([seeds, rest @ ..]) => {
+                if let Some(line) = rest.first() {
+                    return Err(line.error(
+                        data,
+                        Expected::Described("a blank line after the seeds"),
+                        Span::new(0, line.text.len()),
+                    ));
+                }
+                seeds.parse(data, seeds_line)?
+            }
+            _ => return Err(AlmanacError::Empty),
+        };
+
+        let mut maps = Vec::new();
+        let mut categories = vec!["seed".to_string()];
+        for section in sections {
+            let (source, destination) = section[0].parse(data, map_header)?;
+            let expected = categories.last().unwrap();
+            if *expected != source {
+                return Err(AlmanacError::BrokenChain {
+                    expected: expected.clone(),
+                    found: source,
+                });
+            }
+            if categories.contains(&destination) {
+                return Err(AlmanacError::RepeatedCategory(destination));
+            }
+            categories.push(destination.clone());
+
+            let mut map = RangeMap::new();
+            for line in &section[1..] {
+                let (destination_start, source_start, len) = line.parse(data, map_line)?;
+                map.insert(
+                    source_start..source_start + len,
+                    destination_start - source_start,
+                )
+                .map_err(|overlap| AlmanacError::Overlap {
+                    line: line.number,
+                    overlap,
+                })?;
+            }
+
+            maps.push(CategoryMap {
+                source,
+                destination,
+                map,
+            });
+        }
+
+        Ok(Self { seeds, maps })
+    }
+
+    /// Every category in the order the maps lead through them, starting with
+    /// "seed".
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        std::iter::once("seed").chain(self.maps.iter().map(|map| map.destination.as_str()))
+    }
+
+    /// The map from numbers of category `from` to numbers of category `to`,
+    /// composed from every map between them. Going back up the chain runs the
+    /// maps backwards, which only works if each of them is a bijection.
+    pub fn path(&self, from: &str, to: &str) -> Result<RangeMap, PathError> {
+        let position = |category: &str| {
+            self.categories()
+                .position(|name| name == category)
+                .ok_or_else(|| PathError::UnknownCategory(category.to_string()))
+        };
+        let (from, to) = (position(from)?, position(to)?);
+
+        if from <= to {
+            return Ok(self.maps[from..to]
+                .iter()
+                .fold(RangeMap::new(), |path, step| path.then(&step.map)));
+        }
+
+        self.maps[to..from]
+            .iter()
+            .rev()
+            .try_fold(RangeMap::new(), |path, step| {
+                let inverse = step.map.inverse().ok_or_else(|| PathError::NotInvertible {
+                    source: step.source.clone(),
+                    destination: step.destination.clone(),
+                })?;
+                Ok(path.then(&inverse))
+            })
+    }
+}
+
+/// A non-blank line of the almanac, with surrounding whitespace trimmed off.
+struct Line<'i> {
+    /// Numbered from 1.
+    number: usize,
+    /// Where `text` starts in the whole almanac.
+    offset: usize,
+    text: &'i str,
+}
+
+impl<'i> Line<'i> {
+    fn parse<T>(
+        &self,
+        data: &str,
+        mut parser: impl FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+    ) -> Result<T, AlmanacError> {
+        terminated(&mut parser, end)(&mut Parser::new(self.text))
+            .map_err(|error| self.error(data, error.expected().clone(), error.span()))
+    }
+
+    /// An error at `span` within this line, moved to where the line is in
+    /// `data` so that it reports the right line and column.
+    fn error(&self, data: &str, expected: Expected, span: Span) -> AlmanacError {
+        let span = Span::new(self.offset + span.start(), self.offset + span.end());
+        AlmanacError::Parse {
+            line: self.number,
+            error: ParseError::new(expected, span, data),
+        }
+    }
+}
+
+/// Spaces or tabs between numbers.
+fn gap<'i>(parser: &mut Parser<'i>) -> Result<&'i str, ParseError> {
+    take_while1(|ch| ch == ' ' || ch == '\t', "whitespace")(parser)
+}
+
+fn number(parser: &mut Parser<'_>) -> Result<i64, ParseError> {
+    int(parser).map(|value| value as i64)
+}
+
+/// `seeds: 79 14 55 13`
+fn seeds_line(parser: &mut Parser<'_>) -> Result<Vec<i64>, ParseError> {
+    preceded(tag("seeds:"), many1(preceded(gap, number)))(parser)
+}
+
+/// `seed-to-soil map:`
+fn map_header(parser: &mut Parser<'_>) -> Result<(String, String), ParseError> {
+    let category = || take_while1(|ch| ch.is_ascii_alphanumeric(), "a category name");
+    map(
+        tuple((category(), tag("-to-"), category(), tag(" map:"))),
+        |(source, _, destination, _)| (source.to_string(), destination.to_string()),
+    )(parser)
+}
+
+/// `50 98 2`: the destination start, the source start and the length.
+fn map_line(parser: &mut Parser<'_>) -> Result<(i64, i64, i64), ParseError> {
+    tuple((number, preceded(gap, number), preceded(gap, number)))(parser)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Almanac, AlmanacError, Day05, PathError};
+    use utils::Solution;
+
+    const EXAMPLE: &str = include_str!("../../../examples/2023/day05/1.txt");
+
+    #[test]
+    fn looks_up_seeds_from_locations() {
+        let almanac = Day05::parse(EXAMPLE);
+        let seed_to_location = almanac.path("seed", "location").unwrap();
+        let location_to_seed = almanac.path("location", "seed").unwrap();
+        assert_eq!(46, seed_to_location.get(82));
+        assert_eq!(82, location_to_seed.get(46));
+        assert_eq!(13, location_to_seed.get(35));
+
+        // the example's seed 79 has soil 81, fertilizer 81 and water 81
+        assert_eq!(81, almanac.path("soil", "water").unwrap().get(81));
+        assert_eq!(79, almanac.path("water", "seed").unwrap().get(81));
+        assert_eq!(
+            Err(PathError::UnknownCategory("gold".to_string())),
+            almanac.path("seed", "gold")
+        );
+        assert_eq!(
+            vec![
+                "seed",
+                "soil",
+                "fertilizer",
+                "water",
+                "light",
+                "temperature",
+                "humidity",
+                "location"
+            ],
+            almanac.categories().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn tolerates_formatting() {
+        let messy = EXAMPLE
+            .lines()
+            .map(|line| format!("{}  \r\n", line))
+            .collect::<String>()
+            .replace("\r\n  \r\n", "\r\n\r\n\r\n");
+        assert_eq!(Day05::parse(EXAMPLE), Day05::parse(&messy));
+    }
+
+    #[test]
+    fn validates_the_chain() {
+        let broken = "seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n\nwater-to-light map:\n1 2 3\n";
+        assert!(matches!(
+            Almanac::parse(broken),
+            Err(AlmanacError::BrokenChain { expected, found }) if expected == "soil" && found == "water"
+        ));
+
+        let looped = "seeds: 1\n\nseed-to-soil map:\n\nsoil-to-seed map:\n";
+        assert!(matches!(
+            Almanac::parse(looped),
+            Err(AlmanacError::RepeatedCategory(category)) if category == "seed"
+        ));
+
+        let overlapping = "seeds: 1\n\nseed-to-soil map:\n10 0 5\n20 4 5\n";
+        assert!(matches!(
+            Almanac::parse(overlapping),
+            Err(AlmanacError::Overlap { line: 5, .. })
+        ));
+
+        let garbled = "seeds: 1\n\nseed-to-soil map:\n  10 zero 5\n";
+        let err = Almanac::parse(garbled).unwrap_err();
+        assert!(matches!(err, AlmanacError::Parse { line: 4, .. }));
+        assert_eq!(
+            "expected an integer at line 4, column 6, found \"z\"\n --> line 4, column 6\n  |\n4 |   10 zero 5\n  |      ^",
+            err.to_string()
+        );
+        let crowded = "seeds: 1\nseed-to-soil map:\n";
+        assert!(matches!(
+            Almanac::parse(crowded),
+            Err(AlmanacError::Parse { line: 2, error }) if error.found() == "seed-to-soil map:"
+        ));
+        assert!(matches!(Almanac::parse("\n\n"), Err(AlmanacError::Empty)));
+    }
+}
@@ -0,0 +1,167 @@
+use num_bigint::BigUint;
+use std::fmt::Display;
+use tokenizer::{combinators::*, ParseError, Parser};
+use utils::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Race>;
+
+    fn parse(data: &str) -> Self::Input {
+        process_data(data).unwrap_or_else(|err| panic!("Failed to parse input. Message: {}", err))
+    }
+
+    fn part1(races: &Self::Input) -> impl Display {
+        races
+            .iter()
+            .map(Race::ways_to_win)
+            .fold(BigUint::from(1u8), |product, ways| product * ways)
+    }
+
+    fn part2(races: &Self::Input) -> impl Display {
+        // the spaces between numbers were a bad kerning job, so the digits
+        // belong together and can make a number of any size
+        let concatenate = |value: fn(&Race) -> &BigUint| {
+            races
+                .iter()
+                .map(|race| value(race).to_string())
+                .collect::<String>()
+                .parse::<BigUint>()
+                .unwrap_or_default()
+        };
+
+        Race {
+            time: concatenate(|race| &race.time),
+            distance: concatenate(|race| &race.distance),
+        }
+        .ways_to_win()
+    }
+}
+
+/// How long the race lasts, and the record distance to beat.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: BigUint,
+    pub distance: BigUint,
+}
+
+impl Race {
+    /// The number of whole milliseconds `h` the button can be held for to go
+    /// further than the record, which is the number of integers with
+    /// `h * (time - h) > distance`.
+    ///
+    /// The holds that win lie strictly between the roots of
+    /// `h^2 - time * h + distance`, which are `(time ± sqrt(disc)) / 2` with
+    /// `disc = time^2 - 4 * distance`. Everything is done in integers, so there
+    /// is no rounding to go wrong right at a root.
+    pub fn ways_to_win(&self) -> BigUint {
+        let (time, distance) = (&self.time, &self.distance);
+        let squared = time * time;
+        let four_distance = distance * 4u8;
+        if squared <= four_distance {
+            // at most one hold, `time / 2`, gets exactly to the record
+            return BigUint::default();
+        }
+
+        // with s = floor(sqrt(disc)), (time - s) / 2 is at most half a step
+        // above the lower root, so rounding it down lands on either the first
+        // winning hold or the one before it
+        let root = (squared - four_distance).sqrt();
+        let mut shortest = (time - root) / 2u8;
+        if &shortest * (time - &shortest) <= *distance {
+            shortest += 1u8;
+        }
+
+        // holding for `h` goes as far as holding for `time - h`
+        time + 1u8 - shortest * 2u8
+    }
+}
+
+fn process_data(data: &str) -> Result<Vec<Race>, ParseError> {
+    let numbers = |label: &'static str| {
+        preceded(
+            tag(label),
+            many1(preceded(
+                spaces,
+                map(take_while1(|ch| ch.is_ascii_digit(), "a digit"), |digits| {
+                    digits.parse::<BigUint>().expect("digits to be a number")
+                }),
+            )),
+        )
+    };
+
+    let trailing_space = || optional(take_while1(|ch| ch == ' ' || ch == '\t', "whitespace"));
+    let (times, _, _, distances, _, _, _) = tuple((
+        numbers("Time:"),
+        trailing_space(),
+        line_ending,
+        numbers("Distance:"),
+        trailing_space(),
+        optional(line_ending),
+        end,
+    ))(&mut Parser::new(data))?;
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Race;
+    use num_bigint::BigUint;
+
+    fn race(time: u64, distance: u64) -> Race {
+        Race {
+            time: time.into(),
+            distance: distance.into(),
+        }
+    }
+
+    fn brute_force(time: u64, distance: u64) -> u64 {
+        (0..=time).filter(|h| h * (time - h) > distance).count() as u64
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for time in 0..80 {
+            // past time^2 / 4 nothing wins
+            for distance in 0..=time * time / 4 + 2 {
+                assert_eq!(
+                    BigUint::from(brute_force(time, distance)),
+                    race(time, distance).ways_to_win(),
+                    "time {}, distance {}",
+                    time,
+                    distance
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn exact_at_the_boundaries() {
+        // holding for 3 or 7 gets exactly 21, which doesn't beat it
+        assert_eq!(BigUint::from(3u8), race(10, 21).ways_to_win());
+        assert_eq!(BigUint::from(5u8), race(10, 20).ways_to_win());
+
+        // large enough that f64 can't tell the roots apart from their
+        // neighbours: with time = 2k and distance = k^2 - 1 only k wins
+        let k = "123456789012345678901234567890".parse::<BigUint>().unwrap();
+        let squared = &k * &k;
+        let huge = Race {
+            time: &k * 2u8,
+            distance: &squared - 1u8,
+        };
+        assert_eq!(BigUint::from(1u8), huge.ways_to_win());
+
+        // and distance = k^2 - 4 lets k - 1, k and k + 1 win
+        let huge = Race {
+            distance: squared - 4u8,
+            ..huge
+        };
+        assert_eq!(BigUint::from(3u8), huge.ways_to_win());
+    }
+}
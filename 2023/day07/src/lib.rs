@@ -0,0 +1,314 @@
+use std::{collections::HashMap, fmt::Display};
+use tokenizer::{combinators::*, ParseError, Parser};
+use utils::Solution;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+
+    fn parse(data: &str) -> Self::Input {
+        parse_input(data).unwrap_or_else(|err| panic!("Failed to parse input. Message: {}", err))
+    }
+
+    fn part1(hands: &Self::Input) -> impl Display {
+        total_winnings(&Rules::standard(), hands)
+    }
+
+    fn part2(hands: &Self::Input) -> impl Display {
+        total_winnings(&Rules::jokers(), hands)
+    }
+}
+
+fn total_winnings(rules: &Rules, hands: &[Hand]) -> usize {
+    rules
+        .total_winnings(hands)
+        .unwrap_or_else(|err| panic!("Failed to rank hands. Message: {}", err))
+}
+
+fn parse_input(data: &str) -> Result<Vec<Hand>, ParseError> {
+    lines(parse_hand)(&mut Parser::new(data))
+}
+
+fn parse_hand(parser: &mut Parser) -> Result<Hand, ParseError> {
+    let cards = take_while1(|ch| ch.is_ascii_alphanumeric(), "a card");
+    let mut hand = map(tuple((cards, spaces, uint)), |(cards, _, bid)| Hand {
+        cards: cards.chars().collect(),
+        bid,
+    });
+
+    hand(parser)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: Vec<char>,
+    pub bid: usize,
+}
+
+/// A kind of hand, such as a full house, described by the sets of matching
+/// cards it needs. A hand is of the strongest category whose sets it can make,
+/// so the weakest category should need nothing at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Category {
+    pub name: String,
+    /// How many cards each set needs, largest first: `[3, 2]` for a full
+    /// house.
+    groups: Vec<usize>,
+}
+
+impl Category {
+    pub fn new(name: &str, groups: &[usize]) -> Self {
+        let mut groups = groups.to_vec();
+        groups.sort_by(|a, b| b.cmp(a));
+        Self {
+            name: name.to_string(),
+            groups,
+        }
+    }
+
+    pub fn groups(&self) -> &[usize] {
+        &self.groups
+    }
+
+    /// How many wild cards it takes to make this category out of sets of
+    /// matching cards of the given sizes, largest first.
+    fn wilds_needed(&self, sets: &[usize]) -> usize {
+        // the biggest set needed is best made from the biggest set there is,
+        // and so on down
+        self.groups
+            .iter()
+            .enumerate()
+            .map(|(i, needed)| needed.saturating_sub(sets.get(i).copied().unwrap_or(0)))
+            .sum()
+    }
+}
+
+/// How a game of Camel Cards is played.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Every card, weakest first. Ties between hands of the same category
+    /// are broken by comparing their cards in turn in this order.
+    pub order: Vec<char>,
+    /// Cards that stand in for whichever card makes the hand strongest.
+    pub wild: Vec<char>,
+    pub hand_size: usize,
+    /// Weakest first.
+    pub categories: Vec<Category>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RulesError {
+    UnknownCard(char),
+    WrongSize { cards: String, expected: usize },
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownCard(card) => write!(f, "{:?} isn't a card in this game", card),
+            Self::WrongSize { cards, expected } => {
+                write!(f, "{} isn't a hand of {} cards", cards, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+impl Rules {
+    /// The categories of poker hands that can be made from five cards.
+    pub fn poker_categories() -> Vec<Category> {
+        vec![
+            Category::new("high card", &[]),
+            Category::new("one pair", &[2]),
+            Category::new("two pair", &[2, 2]),
+            Category::new("three of a kind", &[3]),
+            Category::new("full house", &[3, 2]),
+            Category::new("four of a kind", &[4]),
+            Category::new("five of a kind", &[5]),
+        ]
+    }
+
+    /// Part 1: no wild cards.
+    pub fn standard() -> Self {
+        Self {
+            order: "23456789TJQKA".chars().collect(),
+            wild: Vec::new(),
+            hand_size: 5,
+            categories: Self::poker_categories(),
+        }
+    }
+
+    /// Part 2: `J` is a joker, which is wild but the weakest card on its own.
+    pub fn jokers() -> Self {
+        Self {
+            order: "J23456789TQKA".chars().collect(),
+            wild: vec!['J'],
+            ..Self::standard()
+        }
+    }
+
+    /// The strongest category `cards` can make, as an index into
+    /// `categories`, or `None` if it makes none of them.
+    pub fn category(&self, cards: &[char]) -> Result<Option<usize>, RulesError> {
+        if cards.len() != self.hand_size {
+            return Err(RulesError::WrongSize {
+                cards: cards.iter().collect(),
+                expected: self.hand_size,
+            });
+        }
+
+        let mut wilds = 0;
+        let mut counts = HashMap::new();
+        for card in cards {
+            if !self.order.contains(card) {
+                return Err(RulesError::UnknownCard(*card));
+            }
+            if self.wild.contains(card) {
+                wilds += 1;
+            } else {
+                *counts.entry(card).or_insert(0) += 1;
+            }
+        }
+
+        let mut sets = counts.into_values().collect::<Vec<_>>();
+        sets.sort_by(|a, b| b.cmp(a));
+        Ok(self
+            .categories
+            .iter()
+            .rposition(|category| category.wilds_needed(&sets) <= wilds))
+    }
+
+    /// What hands are sorted by: the category, then each card's strength.
+    fn key(&self, hand: &Hand) -> Result<(Option<usize>, Vec<usize>), RulesError> {
+        let category = self.category(&hand.cards)?;
+        let strengths = hand
+            .cards
+            .iter()
+            .map(|card| self.order.iter().position(|other| other == card))
+            .collect::<Option<Vec<_>>>()
+            .expect("category to have checked every card");
+        Ok((category, strengths))
+    }
+
+    /// Each hand's bid multiplied by its rank, the weakest hand being rank 1,
+    /// all added up.
+    pub fn total_winnings(&self, hands: &[Hand]) -> Result<usize, RulesError> {
+        let mut ranked = hands
+            .iter()
+            .map(|hand| Ok((self.key(hand)?, hand.bid)))
+            .collect::<Result<Vec<_>, RulesError>>()?;
+        ranked.sort();
+
+        Ok(ranked
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (_, bid))| (rank + 1) * bid)
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Category, Day07, Rules, RulesError};
+    use utils::Solution;
+
+    fn category_name(rules: &Rules, cards: &str) -> String {
+        let cards = cards.chars().collect::<Vec<_>>();
+        let category = rules.category(&cards).unwrap().unwrap();
+        rules.categories[category].name.clone()
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_line() {
+        let err = super::parse_input("32T3K 765\nT55J5 x\n").unwrap_err();
+        assert_eq!(2, err.location().line);
+        assert_eq!(7, err.location().column);
+    }
+
+    #[test]
+    fn basic() {
+        let input = "22J33 120\n222QJ 80";
+        assert_eq!(
+            format!("{}", 280),
+            format!("{}", Day07::part2(&Day07::parse(input)))
+        );
+    }
+
+    #[test]
+    fn categories() {
+        let (standard, jokers) = (Rules::standard(), Rules::jokers());
+        assert_eq!("one pair", category_name(&standard, "32T3K"));
+        assert_eq!("two pair", category_name(&standard, "KTJJT"));
+        assert_eq!("full house", category_name(&standard, "23332"));
+        assert_eq!("high card", category_name(&standard, "23456"));
+        assert_eq!("four of a kind", category_name(&jokers, "KTJJT"));
+        assert_eq!("four of a kind", category_name(&jokers, "QQQJA"));
+        assert_eq!("full house", category_name(&jokers, "22J33"));
+        assert_eq!("five of a kind", category_name(&jokers, "JJJJJ"));
+        assert_eq!("one pair", category_name(&jokers, "2345J"));
+
+        assert_eq!(
+            Err(RulesError::UnknownCard('X')),
+            standard.category(&"23X45".chars().collect::<Vec<_>>())
+        );
+        assert!(matches!(
+            standard.category(&['2', '3']),
+            Err(RulesError::WrongSize { expected: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn jokers_make_the_best_hand() {
+        // against replacing every joker with each other card in turn, where
+        // the only cards worth trying are those in the hand and one that isn't
+        let jokers = Rules::jokers();
+        let standard = Rules::standard();
+        let cards = ['J', '2', '3', '4', 'A'];
+        let replacements = ['2', '3', '4', 'A', 'K'];
+        for index in 0..cards.len().pow(5) {
+            let hand = (0..5)
+                .map(|i| cards[index / cards.len().pow(i) % cards.len()])
+                .collect::<Vec<_>>();
+            let joker_positions = (0..5).filter(|&i| hand[i] == 'J').collect::<Vec<_>>();
+
+            let mut best = None;
+            for choice in 0..replacements.len().pow(joker_positions.len() as u32) {
+                let mut replaced = hand.clone();
+                for (n, &i) in joker_positions.iter().enumerate() {
+                    replaced[i] = replacements
+                        [choice / replacements.len().pow(n as u32) % replacements.len()];
+                }
+                best = best.max(standard.category(&replaced).unwrap());
+            }
+
+            assert_eq!(best, jokers.category(&hand).unwrap(), "{:?}", hand);
+        }
+    }
+
+    #[test]
+    fn variants() {
+        // three cards, with both jokers and twos wild
+        let rules = Rules {
+            order: "J23456789TQKA".chars().collect(),
+            wild: vec!['J', '2'],
+            hand_size: 3,
+            categories: vec![
+                Category::new("high card", &[]),
+                Category::new("pair", &[2]),
+                Category::new("trips", &[3]),
+            ],
+        };
+        let hands = Day07::parse("2KA 10\nJ2A 100\n345 1\n44K 1000\n");
+
+        assert_eq!("pair", category_name(&rules, "2KA"));
+        assert_eq!("trips", category_name(&rules, "J2A"));
+        // 345 < 2KA < 44K < J2A
+        assert_eq!(
+            1 + 2 * 10 + 3 * 1000 + 4 * 100,
+            rules.total_winnings(&hands).unwrap()
+        );
+        assert!(Rules::standard().total_winnings(&hands).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use rayon::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
+use tokenizer::{combinators::*, ParseError, Parser};
+use utils::{
+    math::{self, CrtError},
+    Solution,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    /// The left/right instructions and the network of nodes.
+    type Input = (Vec<Direction>, Nodes);
+
+    fn parse(data: &str) -> Self::Input {
+        parse_input(data).unwrap_or_else(|err| panic!("Failed to parse input. Message: {}", err))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1((directions, nodes): &(Vec<Direction>, Nodes)) -> impl Display {
+    let mut current_location = "AAA".to_string();
+    let mut current = nodes.get("AAA").expect("AAA to exist");
+
+    let mut num_steps = 0;
+    loop {
+        if current_location == "ZZZ" {
+            break;
+        }
+        for direction in directions.iter() {
+            num_steps += 1;
+            match direction {
+                Direction::L => {
+                    current_location = current[0].to_string();
+                    current = nodes.get(&current[0]).expect("left to be defined")
+                }
+                Direction::R => {
+                    current_location = current[1].to_string();
+                    current = nodes.get(&current[1]).expect("right to be defined")
+                }
+            }
+        }
+    }
+
+    num_steps
+}
+
+fn part2((directions, nodes): &(Vec<Direction>, Nodes)) -> impl Display {
+    let mut starts = nodes
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .collect::<Vec<_>>();
+    starts.sort();
+
+    let ghosts = starts
+        .into_par_iter()
+        .map(|start| Ghost::walk(start, directions, nodes))
+        .collect::<Vec<_>>();
+
+    match first_common_hit(&ghosts) {
+        Ok(Some(step)) => step,
+        Ok(None) => panic!("The ghosts are never all on a Z node at once"),
+        Err(err) => panic!("The ghosts' cycles can't be combined: {}", err),
+    }
+}
+
+/// The steps at which one ghost is on a Z node. The ghost's state is its node
+/// and its place in the instructions, so its walk starts repeating once a
+/// state comes round again.
+struct Ghost {
+    /// The step at which the repeating part of the walk starts.
+    cycle_start: u64,
+    cycle_len: u64,
+    /// Steps before `cycle_start` on a Z node, which don't come round again.
+    hits_before: Vec<u64>,
+    /// Steps in the first time round the cycle on a Z node. Each comes round
+    /// again every `cycle_len` steps.
+    hits_in_cycle: Vec<u64>,
+}
+
+impl Ghost {
+    fn walk(start: &str, directions: &[Direction], nodes: &Nodes) -> Self {
+        let mut seen = HashMap::new();
+        let mut hits = Vec::new();
+        let mut location = start;
+        for step in 0u64.. {
+            let instruction = step as usize % directions.len();
+            if let Some(&cycle_start) = seen.get(&(location, instruction)) {
+                let (hits_before, hits_in_cycle) =
+                    hits.into_iter().partition(|hit| *hit < cycle_start);
+                return Self {
+                    cycle_start,
+                    cycle_len: step - cycle_start,
+                    hits_before,
+                    hits_in_cycle,
+                };
+            }
+            seen.insert((location, instruction), step);
+
+            if location.ends_with('Z') {
+                hits.push(step);
+            }
+            let [left, right] = nodes.get(location).expect("node to be defined");
+            location = match directions[instruction] {
+                Direction::L => left,
+                Direction::R => right,
+            };
+        }
+
+        unreachable!("a walk over finitely many states to repeat")
+    }
+
+    fn is_hit(&self, step: u64) -> bool {
+        if step < self.cycle_start {
+            self.hits_before.contains(&step)
+        } else {
+            let step = self.cycle_start + (step - self.cycle_start) % self.cycle_len;
+            self.hits_in_cycle.contains(&step)
+        }
+    }
+}
+
+/// The first step at which every ghost is on a Z node, or `None` if there
+/// isn't one.
+fn first_common_hit(ghosts: &[Ghost]) -> Result<Option<u64>, CrtError> {
+    // until every ghost is in its cycle, there's nothing to do but check each
+    // step
+    let Some(settled) = ghosts.iter().map(|ghost| ghost.cycle_start).max() else {
+        return Ok(None);
+    };
+    if let Some(step) = (0..settled).find(|step| ghosts.iter().all(|ghost| ghost.is_hit(*step))) {
+        return Ok(Some(step));
+    }
+
+    // from then on, a ghost is on a Z node at the steps congruent to one of
+    // its hits in the cycle. The steps at which all the ghosts seen so far
+    // are on a Z node are a set of residues modulo the lcm of their cycle
+    // lengths, which is merged with each ghost's hits in turn. The set never
+    // holds more residues than the lcm, however many ghosts there are.
+    let mut residues = BTreeSet::from([0]);
+    let mut modulus = 1;
+    for ghost in ghosts {
+        let mut merged = BTreeSet::new();
+        for residue in residues.iter() {
+            for hit in ghost.hits_in_cycle.iter() {
+                match math::crt(&[(*residue, modulus), (*hit as i64, ghost.cycle_len as i64)]) {
+                    Ok((step, _)) => {
+                        merged.insert(step);
+                    }
+                    Err(CrtError::NoSolution) => {}
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+
+        if merged.is_empty() {
+            return Ok(None);
+        }
+        residues = merged;
+        modulus = math::crt(&[(0, modulus), (0, ghost.cycle_len as i64)])?.1;
+    }
+
+    let modulus = modulus as u64;
+    Ok(residues
+        .into_iter()
+        .map(|step| {
+            let step = step as u64;
+            // the first step at or after `settled` in this residue class
+            step + settled.saturating_sub(step).div_ceil(modulus) * modulus
+        })
+        .min())
+}
+
+pub type Nodes = HashMap<String, [String; 2]>;
+
+fn parse_input(data: &str) -> Result<(Vec<Direction>, Nodes), ParseError> {
+    let directions = many1(alt((
+        map(char('L'), |_| Direction::L),
+        map(char('R'), |_| Direction::R),
+    )));
+
+    let label = || take_while1(|ch| ch.is_ascii_alphanumeric(), "a node label");
+    let node = tuple((
+        label(),
+        tag(" = "),
+        delimited(char('('), tuple((label(), tag(", "), label())), char(')')),
+    ));
+
+    let (directions, _, _, nodes) =
+        tuple((directions, line_ending, line_ending, lines(node)))(&mut Parser::new(data))?;
+
+    let mut map = HashMap::new();
+    for (id, _, (left, _, right)) in nodes {
+        if map
+            .insert(id.to_string(), [left.to_string(), right.to_string()])
+            .is_some()
+        {
+            panic!("Duplicate key {}", id);
+        }
+    }
+
+    Ok((directions, map))
+}
+
+pub enum Direction {
+    L,
+    R,
+}
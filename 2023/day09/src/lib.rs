@@ -0,0 +1,84 @@
+use std::fmt::Display;
+use tokenizer::Parser;
+use utils::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<isize>>;
+
+    fn parse(data: &str) -> Self::Input {
+        parse_data(data)
+    }
+
+    fn part1(sequences: &Self::Input) -> impl Display {
+        sequences
+            .iter()
+            .map(|sequence| {
+                difference_table(sequence)
+                    .iter()
+                    .rev()
+                    .skip(1)
+                    .fold(0, |next, row| row[row.len() - 1] + next)
+            })
+            .sum::<isize>()
+    }
+
+    fn part2(sequences: &Self::Input) -> impl Display {
+        sequences
+            .iter()
+            .map(|sequence| {
+                difference_table(sequence)
+                    .iter()
+                    .rev()
+                    .skip(1)
+                    .fold(0, |previous, row| row[0] - previous)
+            })
+            .sum::<isize>()
+    }
+}
+
+/// The sequence itself followed by each row of differences, down to the first
+/// row of all 0s.
+fn difference_table(sequence: &[isize]) -> Vec<Vec<isize>> {
+    let mut sequence_stack = vec![sequence.to_vec()];
+
+    let mut all_0s = false;
+    while !all_0s {
+        all_0s = true;
+        let mut new_sequence = Vec::new();
+
+        for slice in sequence_stack.last().unwrap().windows(2) {
+            let new_val = slice[1] - slice[0];
+            if new_val != 0 {
+                all_0s = false;
+            }
+            new_sequence.push(new_val);
+        }
+
+        sequence_stack.push(new_sequence);
+    }
+
+    sequence_stack
+}
+
+fn parse_data(data: &str) -> Vec<Vec<isize>> {
+    let mut results = Vec::new();
+
+    for line in data.lines() {
+        let mut parser = Parser::new(line);
+        let mut line_result = Vec::new();
+
+        loop {
+            parser.match_char_while(|ch| ch == ' ');
+            line_result.push(parser.match_int().expect("To see int").1);
+
+            if parser.peek_position() == line.len() {
+                break;
+            }
+        }
+        results.push(line_result);
+    }
+
+    results
+}
@@ -1,15 +0,0 @@
-use std::fmt::Display;
-
-fn main() {
-    let data = include_str!("../data.txt");
-    println!("part 1: {}", part1(data));
-    println!("part 2: {}", part2(data));
-}
-
-fn part1(data: &str) -> impl Display {
-    ""
-}
-
-fn part2(data: &str) -> impl Display {
-    ""
-}
@@ -0,0 +1,382 @@
+//! Small parser combinators built on top of `Parser`.
+//!
+//! A combinator is any `FnMut(&mut Parser) -> Result<T, ParseError>`, so the
+//! `expect_*` methods on `Parser` can be mixed freely with the functions in
+//! this module. Every combinator here is atomic: when it fails, the parser is
+//! rewound to where it was before the combinator ran, which makes it safe to
+//! try alternatives with `alt` or `optional`.
+//!
+//! ```
+//! use tokenizer::{combinators::*, Parser};
+//!
+//! let mut parser = Parser::new("Game 3: 8 green, 6 blue; 1 red");
+//! let cube = tuple((uint, tag(" "), alt((tag("red"), tag("green"), tag("blue")))));
+//! let round = sep_by1(map(cube, |(count, _, _)| count), tag(", "));
+//! let mut game = tuple((preceded(tag("Game "), uint), tag(": "), sep_by1(round, tag("; "))));
+//!
+//! let (number, _, rounds) = game(&mut parser).unwrap();
+//! assert_eq!(3, number);
+//! assert_eq!(vec![vec![8, 6], vec![1]], rounds);
+//! ```
+
+use crate::{Expected, ParseError, Parser};
+
+/// Matches the literal string `str`.
+pub fn tag<'i>(str: &'static str) -> impl FnMut(&mut Parser<'i>) -> Result<&'i str, ParseError> {
+    move |parser| {
+        let span = parser.expect_str(str)?;
+        Ok(&parser.source[span.start()..span.end()])
+    }
+}
+
+/// Matches the single character `ch`.
+pub fn char<'i>(ch: char) -> impl FnMut(&mut Parser<'i>) -> Result<char, ParseError> {
+    move |parser| parser.expect_char(ch).map(|_| ch)
+}
+
+/// Matches a single character satisfying `predicate` and returns it.
+pub fn satisfy<'i, P: FnMut(char) -> bool>(
+    mut predicate: P,
+    description: &'static str,
+) -> impl FnMut(&mut Parser<'i>) -> Result<char, ParseError> {
+    move |parser| {
+        let span = parser.expect_char_if(&mut predicate, description)?;
+        Ok(parser.source[span.start()..]
+            .chars()
+            .next()
+            .expect("span to cover a character"))
+    }
+}
+
+/// Matches one or more characters satisfying `predicate` and returns them.
+pub fn take_while1<'i, P: FnMut(char) -> bool>(
+    mut predicate: P,
+    description: &'static str,
+) -> impl FnMut(&mut Parser<'i>) -> Result<&'i str, ParseError> {
+    move |parser| {
+        let span = parser.expect_char_while(&mut predicate, description)?;
+        Ok(&parser.source[span.start()..span.end()])
+    }
+}
+
+/// Matches one or more spaces.
+pub fn spaces<'i>(parser: &mut Parser<'i>) -> Result<&'i str, ParseError> {
+    take_while1(|ch| ch == ' ', "a space")(parser)
+}
+
+pub fn uint(parser: &mut Parser<'_>) -> Result<usize, ParseError> {
+    parser.expect_uint().map(|(_, value)| value)
+}
+
+pub fn int(parser: &mut Parser<'_>) -> Result<isize, ParseError> {
+    parser.expect_int().map(|(_, value)| value)
+}
+
+/// Succeeds only when the whole source has been consumed.
+pub fn end(parser: &mut Parser<'_>) -> Result<(), ParseError> {
+    parser.expect_end()
+}
+
+/// Matches a line break, either "\n" or "\r\n".
+pub fn line_ending<'i>(parser: &mut Parser<'i>) -> Result<&'i str, ParseError> {
+    alt((tag("\n"), tag("\r\n")))(parser)
+        .map_err(|_| parser.error(Expected::Described("end of line")))
+}
+
+/// Matches `item` once on every line through to the end of the source. Each
+/// line has to be matched in full, and the last one may end with a line
+/// break. Errors from `item` are passed on as they are, so they point at the
+/// offending line.
+pub fn lines<'i, T, P>(mut item: P) -> impl FnMut(&mut Parser<'i>) -> Result<Vec<T>, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+{
+    move |parser| {
+        atomic(parser, |parser| {
+            let mut results = Vec::new();
+            while parser.peek().is_some() {
+                results.push(item(parser)?);
+                if parser.peek().is_some() {
+                    line_ending(parser)?;
+                }
+            }
+            Ok(results)
+        })
+    }
+}
+
+/// Transforms the output of `inner` with `f`.
+pub fn map<'i, T, U, P, F>(
+    mut inner: P,
+    mut f: F,
+) -> impl FnMut(&mut Parser<'i>) -> Result<U, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+    F: FnMut(T) -> U,
+{
+    move |parser| inner(parser).map(&mut f)
+}
+
+/// Returns `None` instead of failing when `inner` does not match.
+pub fn optional<'i, T, P>(
+    mut inner: P,
+) -> impl FnMut(&mut Parser<'i>) -> Result<Option<T>, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+{
+    move |parser| Ok(atomic(parser, &mut inner).ok())
+}
+
+/// Matches `inner` as many times as possible, including zero times.
+pub fn many0<'i, T, P>(mut inner: P) -> impl FnMut(&mut Parser<'i>) -> Result<Vec<T>, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+{
+    move |parser| {
+        let mut results = Vec::new();
+        loop {
+            let start = parser.peek_position();
+            match atomic(parser, &mut inner) {
+                // stop on empty matches so we don't loop forever
+                Ok(result) if parser.peek_position() != start => results.push(result),
+                _ => return Ok(results),
+            }
+        }
+    }
+}
+
+/// Matches `inner` as many times as possible, at least once.
+pub fn many1<'i, T, P>(mut inner: P) -> impl FnMut(&mut Parser<'i>) -> Result<Vec<T>, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+{
+    move |parser| {
+        let first = atomic(parser, &mut inner)?;
+        let mut results = many0(&mut inner)(parser)?;
+        results.insert(0, first);
+        Ok(results)
+    }
+}
+
+/// Matches zero or more `item`s separated by `separator`. A trailing
+/// separator is not consumed.
+pub fn sep_by<'i, T, S, P, Q>(
+    mut item: P,
+    mut separator: Q,
+) -> impl FnMut(&mut Parser<'i>) -> Result<Vec<T>, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+    Q: FnMut(&mut Parser<'i>) -> Result<S, ParseError>,
+{
+    move |parser| match atomic(parser, &mut item) {
+        Err(_) => Ok(Vec::new()),
+        Ok(first) => {
+            let mut results = vec![first];
+            results.extend(many0(preceded(&mut separator, &mut item))(parser)?);
+            Ok(results)
+        }
+    }
+}
+
+/// Matches one or more `item`s separated by `separator`. A trailing
+/// separator is not consumed.
+pub fn sep_by1<'i, T, S, P, Q>(
+    mut item: P,
+    mut separator: Q,
+) -> impl FnMut(&mut Parser<'i>) -> Result<Vec<T>, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+    Q: FnMut(&mut Parser<'i>) -> Result<S, ParseError>,
+{
+    move |parser| {
+        let first = atomic(parser, &mut item)?;
+        let mut results = vec![first];
+        results.extend(many0(preceded(&mut separator, &mut item))(parser)?);
+        Ok(results)
+    }
+}
+
+/// Matches `open`, `inner` and `close` in order, keeping only `inner`.
+pub fn delimited<'i, O, T, C, P, Q, R>(
+    open: P,
+    inner: Q,
+    close: R,
+) -> impl FnMut(&mut Parser<'i>) -> Result<T, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<O, ParseError>,
+    Q: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+    R: FnMut(&mut Parser<'i>) -> Result<C, ParseError>,
+{
+    map(tuple((open, inner, close)), |(_, inner, _)| inner)
+}
+
+/// Matches `prefix` then `inner`, keeping only `inner`.
+pub fn preceded<'i, O, T, P, Q>(
+    prefix: P,
+    inner: Q,
+) -> impl FnMut(&mut Parser<'i>) -> Result<T, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<O, ParseError>,
+    Q: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+{
+    map(tuple((prefix, inner)), |(_, inner)| inner)
+}
+
+/// Matches `inner` then `suffix`, keeping only `inner`.
+pub fn terminated<'i, T, S, P, Q>(
+    inner: P,
+    suffix: Q,
+) -> impl FnMut(&mut Parser<'i>) -> Result<T, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+    Q: FnMut(&mut Parser<'i>) -> Result<S, ParseError>,
+{
+    map(tuple((inner, suffix)), |(inner, _)| inner)
+}
+
+/// Matches every parser in the tuple in order and returns a tuple of their
+/// outputs.
+pub fn tuple<'i, O, S: Sequence<'i, O>>(
+    mut sequence: S,
+) -> impl FnMut(&mut Parser<'i>) -> Result<O, ParseError> {
+    move |parser| atomic(parser, |parser| sequence.parse_sequence(parser))
+}
+
+/// Tries every parser in the tuple in order and returns the output of the
+/// first one that matches. If none match, the error from the alternative that
+/// got furthest into the source is returned.
+pub fn alt<'i, T, A: Alternatives<'i, T>>(
+    mut alternatives: A,
+) -> impl FnMut(&mut Parser<'i>) -> Result<T, ParseError> {
+    move |parser| alternatives.parse_alternatives(parser)
+}
+
+/// Runs `inner`, rewinding the parser if it fails.
+fn atomic<'i, T, P>(parser: &mut Parser<'i>, mut inner: P) -> Result<T, ParseError>
+where
+    P: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+{
+    let checkpoint = parser.checkpoint();
+    let result = inner(parser);
+    if result.is_err() {
+        parser.rewind(checkpoint);
+    }
+    result
+}
+
+/// A tuple of parsers that can be run one after the other. See `tuple`.
+pub trait Sequence<'i, O> {
+    fn parse_sequence(&mut self, parser: &mut Parser<'i>) -> Result<O, ParseError>;
+}
+
+/// A tuple of parsers with the same output type that can be tried in turn.
+/// See `alt`.
+pub trait Alternatives<'i, T> {
+    fn parse_alternatives(&mut self, parser: &mut Parser<'i>) -> Result<T, ParseError>;
+}
+
+macro_rules! impl_tuples {
+    ($($parser:ident $output:ident $index:tt),+) => {
+        impl<'i, $($output, $parser),+> Sequence<'i, ($($output,)+)> for ($($parser,)+)
+        where
+            $($parser: FnMut(&mut Parser<'i>) -> Result<$output, ParseError>),+
+        {
+            fn parse_sequence(&mut self, parser: &mut Parser<'i>) -> Result<($($output,)+), ParseError> {
+                Ok(($((self.$index)(parser)?,)+))
+            }
+        }
+
+        impl<'i, T, $($parser),+> Alternatives<'i, T> for ($($parser,)+)
+        where
+            $($parser: FnMut(&mut Parser<'i>) -> Result<T, ParseError>),+
+        {
+            fn parse_alternatives(&mut self, parser: &mut Parser<'i>) -> Result<T, ParseError> {
+                let mut furthest: Option<ParseError> = None;
+                $(
+                    match atomic(parser, &mut self.$index) {
+                        Ok(result) => return Ok(result),
+                        Err(err) => {
+                            if furthest
+                                .as_ref()
+                                .is_none_or(|furthest| furthest.span().start() < err.span().start())
+                            {
+                                furthest = Some(err);
+                            }
+                        }
+                    }
+                )+
+                Err(furthest.unwrap_or_else(|| parser.error(Expected::Described("an alternative"))))
+            }
+        }
+    };
+}
+
+impl_tuples!(P0 O0 0);
+impl_tuples!(P0 O0 0, P1 O1 1);
+impl_tuples!(P0 O0 0, P1 O1 1, P2 O2 2);
+impl_tuples!(P0 O0 0, P1 O1 1, P2 O2 2, P3 O3 3);
+impl_tuples!(P0 O0 0, P1 O1 1, P2 O2 2, P3 O3 3, P4 O4 4);
+impl_tuples!(P0 O0 0, P1 O1 1, P2 O2 2, P3 O3 3, P4 O4 4, P5 O5 5);
+impl_tuples!(P0 O0 0, P1 O1 1, P2 O2 2, P3 O3 3, P4 O4 4, P5 O5 5, P6 O6 6);
+impl_tuples!(P0 O0 0, P1 O1 1, P2 O2 2, P3 O3 3, P4 O4 4, P5 O5 5, P6 O6 6, P7 O7 7);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alt_backtracks_over_shared_prefixes() {
+        let mut parser = Parser::new("blue");
+        let mut color = alt((tag("black"), tag("blue")));
+        assert_eq!("blue", color(&mut parser).unwrap());
+        assert!(parser.expect_end().is_ok());
+    }
+
+    #[test]
+    fn alt_reports_furthest_error() {
+        let mut parser = Parser::new("12 gren");
+        let mut cube = alt((
+            map(tag("none"), |_| 0),
+            map(tuple((uint, spaces, tag("green"))), |(count, _, _)| count),
+        ));
+        let err = cube(&mut parser).unwrap_err();
+        assert_eq!(6, err.span().start());
+        assert_eq!(0, parser.peek_position());
+    }
+
+    #[test]
+    fn sep_by_leaves_trailing_separator() {
+        let mut parser = Parser::new("1, 2, 3, x");
+        let mut numbers = sep_by(int, tag(", "));
+        assert_eq!(vec![1, 2, 3], numbers(&mut parser).unwrap());
+        assert_eq!(7, parser.peek_position());
+
+        let mut parser = Parser::new("x");
+        assert_eq!(Vec::<isize>::new(), numbers(&mut parser).unwrap());
+    }
+
+    #[test]
+    fn lines_point_at_the_bad_line() {
+        let mut numbers = lines(sep_by1(uint, spaces));
+        let mut parser = Parser::new("1 2\r\n3\n");
+        assert_eq!(vec![vec![1, 2], vec![3]], numbers(&mut parser).unwrap());
+        assert!(parser.expect_end().is_ok());
+
+        let mut parser = Parser::new("1 2\n3\nx 4");
+        let err = numbers(&mut parser).unwrap_err();
+        assert_eq!(6, err.span().start());
+        assert_eq!(3, err.location().line);
+        assert_eq!(0, parser.peek_position());
+    }
+
+    #[test]
+    fn optional_and_many() {
+        let mut parser = Parser::new("-5 7 8");
+        let sign = optional(char('-'));
+        let mut signed = tuple((sign, uint, many0(preceded(spaces, uint))));
+        assert_eq!((Some('-'), 5, vec![7, 8]), signed(&mut parser).unwrap());
+
+        let mut parser = Parser::new("abc");
+        assert!(many1(char('x'))(&mut parser).is_err());
+    }
+}
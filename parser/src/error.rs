@@ -0,0 +1,93 @@
+use std::{error::Error, fmt::Display, sync::Arc};
+
+use crate::{Location, SourceMap, Span};
+
+/// Describes the token a matcher was looking for when it failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    Str(String),
+    Char(char),
+    /// A character or run of characters satisfying a predicate. The string
+    /// is a human readable description, for example "a symbol".
+    Described(&'static str),
+    UInt,
+    Int,
+    EndOfInput,
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Str(str) => write!(f, "{:?}", str),
+            Self::Char(ch) => write!(f, "{:?}", ch),
+            Self::Described(description) => write!(f, "{}", description),
+            Self::UInt => write!(f, "an unsigned integer"),
+            Self::Int => write!(f, "an integer"),
+            Self::EndOfInput => write!(f, "end of input"),
+        }
+    }
+}
+
+/// The error returned by the `expect_*` family of matchers on `Parser`. It
+/// holds on to the source being parsed so it can be reported after the parser
+/// has gone away. The source is shared rather than copied, as combinators
+/// build and throw away an error every time they backtrack.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    expected: Expected,
+    span: Span,
+    source: Arc<str>,
+}
+
+impl ParseError {
+    pub fn new(expected: Expected, span: Span, source: impl Into<Arc<str>>) -> Self {
+        Self {
+            expected,
+            span,
+            source: source.into(),
+        }
+    }
+
+    pub fn expected(&self) -> &Expected {
+        &self.expected
+    }
+
+    /// The span of the offending text. This is empty when the parser ran out
+    /// of input.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn location(&self) -> Location {
+        SourceMap::new(&self.source).location(self.span.start())
+    }
+
+    /// The text the parser found instead of the expected token.
+    pub fn found(&self) -> &str {
+        &self.source[self.span.start()..self.span.end()]
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source_map = SourceMap::new(&self.source);
+        write!(
+            f,
+            "expected {} at {}, ",
+            self.expected,
+            source_map.location(self.span.start())
+        )?;
+        if self.span.start() == self.span.end() {
+            writeln!(f, "found end of input")?;
+        } else {
+            writeln!(f, "found {:?}", self.found())?;
+        }
+        write!(f, "{}", source_map.render(self.span))
+    }
+}
+
+impl Error for ParseError {}
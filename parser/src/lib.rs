@@ -1,9 +1,24 @@
-use core::num;
-use std::{iter::Peekable, str::CharIndices};
+use std::{cell::OnceCell, iter::Peekable, str::CharIndices, sync::Arc};
+
+// lets code generated by the derive macro refer to `::tokenizer` from in here
+extern crate self as tokenizer;
+
+pub mod combinators;
+mod error;
+mod puzzle_input;
+mod source_map;
+
+pub use error::{Expected, ParseError};
+pub use puzzle_input::{__private, parse_lines, FromPuzzleInput, FromSeparated};
+pub use source_map::{Location, SourceMap};
+pub use tokenizer_derive::FromPuzzleInput;
 
 pub struct Parser<'i> {
     pub source: &'i str,
     chars_iter: Peekable<CharIndices<'i>>,
+    /// The copy of `source` every error built by this parser shares. It is
+    /// made the first time one is needed.
+    error_source: OnceCell<Arc<str>>,
 }
 
 impl<'i> Parser<'i> {
@@ -11,9 +26,23 @@ impl<'i> Parser<'i> {
         Self {
             source,
             chars_iter: source.char_indices().peekable(),
+            error_source: OnceCell::new(),
+        }
+    }
+
+    /// Saves the current position so that it can be returned to later with
+    /// `rewind`.
+    pub fn checkpoint(&mut self) -> Checkpoint<'i> {
+        Checkpoint {
+            position: self.peek_position(),
+            chars_iter: self.chars_iter.clone(),
         }
     }
 
+    pub fn rewind(&mut self, checkpoint: Checkpoint<'i>) {
+        self.chars_iter = checkpoint.chars_iter;
+    }
+
     pub fn peek(&mut self) -> Option<char> {
         self.chars_iter.peek().into_iter().map(|opt| opt.1).next()
     }
@@ -25,16 +54,19 @@ impl<'i> Parser<'i> {
         }
     }
 
+    /// Matches the whole of `str`. Nothing is consumed unless every character
+    /// matches.
     pub fn match_str(&mut self, str: &str) -> Option<Span> {
-        let start = self.peek_position();
+        let checkpoint = self.checkpoint();
 
         for char in str.chars() {
             if self.match_char(char).is_none() {
+                self.rewind(checkpoint);
                 return None;
             }
         }
 
-        Span::new(start, self.peek_position()).as_opt()
+        Span::new(checkpoint.position, self.peek_position()).as_opt()
     }
 
     pub fn match_char(&mut self, ch: char) -> Option<Span> {
@@ -44,12 +76,9 @@ impl<'i> Parser<'i> {
     pub fn match_char_if<P: FnMut(char) -> bool>(&mut self, mut predicate: P) -> Option<Span> {
         let start = self.peek_position();
 
-        match self.chars_iter.peek() {
-            None => {}
-            Some((_, source_ch)) => {
-                if predicate(*source_ch) {
-                    self.chars_iter.next();
-                }
+        if let Some((_, source_ch)) = self.chars_iter.peek() {
+            if predicate(*source_ch) {
+                self.chars_iter.next();
             }
         }
 
@@ -68,45 +97,153 @@ impl<'i> Parser<'i> {
         Span::new(start, self.peek_position()).as_opt()
     }
 
+    /// Matches a run of digits. Nothing is consumed if the value doesn't fit
+    /// in a `usize`.
     pub fn match_uint(&mut self) -> Option<(Span, usize)> {
-        let start = self.peek_position();
+        let checkpoint = self.checkpoint();
+        let span = self.match_char_while(|ch| ch.is_ascii_digit())?;
 
-        let mut num_str = String::new();
-        loop {
-            match self.chars_iter.peek() {
-                None => break,
-                Some((_, ch)) => match ch {
-                    '0'..='9' => {
-                        num_str.push(*ch);
-                        self.chars_iter.next();
-                    }
-                    _ => break,
-                },
+        match self.source[span.start()..span.end()].parse::<usize>() {
+            Ok(value) => Some((span, value)),
+            Err(_) => {
+                self.rewind(checkpoint);
+                None
             }
         }
+    }
 
-        if num_str.len() == 0 || num_str.len() == 1 && num_str[0..1] == *"-" {
+    /// Matches an optionally negative integer. A lone '-' is not consumed, and
+    /// neither is a value that doesn't fit in an `isize`.
+    pub fn match_int(&mut self) -> Option<(Span, isize)> {
+        let checkpoint = self.checkpoint();
+
+        let is_negative = self.match_char('-').is_some();
+        let numeric_value = self.match_uint().and_then(|(_, value)| {
+            if is_negative {
+                0isize.checked_sub_unsigned(value)
+            } else {
+                isize::try_from(value).ok()
+            }
+        });
+        let Some(numeric_value) = numeric_value else {
+            self.rewind(checkpoint);
             return None;
-        }
+        };
 
-        return Some((
-            Span::new(start, self.peek_position()),
-            num_str
-                .parse::<usize>()
-                .expect("string to be a valid integer at this point."),
-        ));
+        Some((
+            Span::new(checkpoint.position, self.peek_position()),
+            numeric_value,
+        ))
     }
 
-    pub fn match_int(&mut self) -> Option<(Span, isize)> {
+    pub fn expect_str(&mut self, str: &str) -> Result<Span, ParseError> {
         let start = self.peek_position();
+        let mismatch = self.source[start..]
+            .char_indices()
+            .zip(str.chars())
+            .find(|((_, source_ch), ch)| source_ch != ch)
+            .map(|((i, _), _)| start + i)
+            .unwrap_or_else(|| (start + str.len()).min(self.source.len()));
 
-        let is_negative = self.match_char('-').is_some();
-        let mut numeric_value = self.match_uint()?.1 as isize;
-        if is_negative {
-            numeric_value = -numeric_value
+        self.match_str(str)
+            .ok_or_else(|| self.error_at(mismatch, Expected::Str(str.to_string())))
+    }
+
+    pub fn expect_char(&mut self, ch: char) -> Result<Span, ParseError> {
+        self.match_char(ch)
+            .ok_or_else(|| self.error(Expected::Char(ch)))
+    }
+
+    pub fn expect_char_if<P: FnMut(char) -> bool>(
+        &mut self,
+        predicate: P,
+        description: &'static str,
+    ) -> Result<Span, ParseError> {
+        self.match_char_if(predicate)
+            .ok_or_else(|| self.error(Expected::Described(description)))
+    }
+
+    pub fn expect_char_while<P: FnMut(char) -> bool>(
+        &mut self,
+        predicate: P,
+        description: &'static str,
+    ) -> Result<Span, ParseError> {
+        self.match_char_while(predicate)
+            .ok_or_else(|| self.error(Expected::Described(description)))
+    }
+
+    pub fn expect_uint(&mut self) -> Result<(Span, usize), ParseError> {
+        let start = self.peek_position();
+        self.match_uint()
+            .ok_or_else(|| match self.digits_end(start) {
+                end if end > start => self.error_in(
+                    Span::new(start, end),
+                    Expected::Described("an unsigned integer in range"),
+                ),
+                _ => self.error(Expected::UInt),
+            })
+    }
+
+    pub fn expect_int(&mut self) -> Result<(Span, isize), ParseError> {
+        let start = self.peek_position();
+        let digits_start = start + usize::from(self.source[start..].starts_with('-'));
+        self.match_int()
+            .ok_or_else(|| match self.digits_end(digits_start) {
+                end if end > digits_start => self.error_in(
+                    Span::new(start, end),
+                    Expected::Described("an integer in range"),
+                ),
+                _ => self.error(Expected::Int),
+            })
+    }
+
+    pub fn expect_end(&mut self) -> Result<(), ParseError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.error(Expected::EndOfInput)),
         }
+    }
+
+    /// Builds an error pointing at the next character in the source.
+    pub fn error(&mut self, expected: Expected) -> ParseError {
+        let position = self.peek_position();
+        self.error_at(position, expected)
+    }
 
-        return Some((Span::new(start, self.peek_position()), numeric_value));
+    fn error_at(&self, position: usize, expected: Expected) -> ParseError {
+        let end = self.source[position..]
+            .chars()
+            .next()
+            .map(|ch| position + ch.len_utf8())
+            .unwrap_or(position);
+
+        self.error_in(Span::new(position, end), expected)
+    }
+
+    fn error_in(&self, span: Span, expected: Expected) -> ParseError {
+        let source = self.error_source.get_or_init(|| self.source.into());
+        ParseError::new(expected, span, source.clone())
+    }
+
+    /// The end of the run of digits starting at `start`.
+    fn digits_end(&self, start: usize) -> usize {
+        self.source[start..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .map_or(self.source.len(), |i| start + i)
+    }
+}
+
+/// A saved parser position. See `Parser::checkpoint`.
+#[derive(Clone)]
+pub struct Checkpoint<'i> {
+    position: usize,
+    chars_iter: Peekable<CharIndices<'i>>,
+}
+
+impl Checkpoint<'_> {
+    /// The byte offset the checkpoint was taken at.
+    pub fn position(&self) -> usize {
+        self.position
     }
 }
 
@@ -137,3 +274,132 @@ impl Span {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Expected, Location, Parser, SourceMap, Span};
+
+    #[test]
+    fn expect_str_points_at_mismatch() {
+        let mut parser = Parser::new("Gamf 3");
+        let err = parser.expect_str("Game ").unwrap_err();
+        assert_eq!(&Expected::Str("Game ".to_string()), err.expected());
+        assert_eq!(Span::new(3, 4), err.span());
+        assert_eq!("f", err.found());
+    }
+
+    #[test]
+    fn expect_at_end_of_input() {
+        let mut parser = Parser::new("12");
+        assert_eq!(12, parser.expect_uint().unwrap().1);
+        let err = parser.expect_char(':').unwrap_err();
+        assert_eq!(Span::new(2, 2), err.span());
+        assert_eq!(
+            "expected ':' at line 1, column 3, found end of input\n --> line 1, column 3\n  |\n1 | 12\n  |   ^",
+            err.to_string()
+        );
+        assert!(parser.expect_end().is_ok());
+    }
+
+    #[test]
+    fn expect_int_reports_found_text() {
+        let mut parser = Parser::new("x1");
+        let err = parser.expect_int().unwrap_err();
+        assert_eq!(
+            "expected an integer at line 1, column 1, found \"x\"",
+            err.to_string().lines().next().unwrap()
+        );
+    }
+
+    #[test]
+    fn integers_out_of_range() {
+        let mut parser = Parser::new("99999999999999999999 x");
+        assert_eq!(None, parser.match_uint());
+        assert_eq!(0, parser.peek_position());
+        let err = parser.expect_uint().unwrap_err();
+        assert_eq!(Span::new(0, 20), err.span());
+        assert_eq!(
+            &Expected::Described("an unsigned integer in range"),
+            err.expected()
+        );
+
+        let mut parser = Parser::new("-9223372036854775809");
+        assert_eq!(None, parser.match_int());
+        assert_eq!(Span::new(0, 20), parser.expect_int().unwrap_err().span());
+
+        let mut parser = Parser::new("-9223372036854775808");
+        assert_eq!(isize::MIN, parser.expect_int().unwrap().1);
+        let mut parser = Parser::new("9223372036854775808");
+        assert!(parser.expect_int().is_err());
+    }
+
+    #[test]
+    fn match_str_does_not_consume_partial_prefix() {
+        let mut parser = Parser::new("green");
+        assert_eq!(None, parser.match_str("grey"));
+        assert_eq!(0, parser.peek_position());
+        assert_eq!(None, parser.match_str("greenish"));
+        assert_eq!(0, parser.peek_position());
+        assert_eq!(Some(Span::new(0, 5)), parser.match_str("green"));
+    }
+
+    #[test]
+    fn match_int_does_not_consume_lone_minus() {
+        let mut parser = Parser::new("-x");
+        assert_eq!(None, parser.match_int());
+        assert_eq!(Some('-'), parser.peek());
+
+        let mut parser = Parser::new("-12");
+        assert_eq!(Some((Span::new(0, 3), -12)), parser.match_int());
+    }
+
+    #[test]
+    fn rewind_to_checkpoint() {
+        let mut parser = Parser::new("Game 1: 3 blue");
+        parser.match_str("Game ");
+        let checkpoint = parser.checkpoint();
+        assert_eq!(5, checkpoint.position());
+
+        parser.match_uint();
+        parser.match_str(": ");
+        assert_eq!(8, parser.peek_position());
+
+        parser.rewind(checkpoint);
+        assert_eq!(5, parser.peek_position());
+        assert_eq!(1, parser.match_uint().unwrap().1);
+    }
+
+    #[test]
+    fn source_map_locations() {
+        let source_map = SourceMap::new("ab\r\nçd\n\nxyz");
+        assert_eq!(Location { line: 1, column: 1 }, source_map.location(0));
+        assert_eq!(Location { line: 2, column: 2 }, source_map.location(6));
+        assert_eq!(Location { line: 3, column: 1 }, source_map.location(8));
+        assert_eq!(Location { line: 4, column: 4 }, source_map.location(12));
+        assert_eq!("ab", source_map.line(1));
+        assert_eq!("çd", source_map.line(2));
+    }
+
+    #[test]
+    fn source_map_offsets_inside_characters() {
+        // 'ç' is bytes 4 and 5
+        let source_map = SourceMap::new("ab\r\nçd");
+        assert_eq!(Location { line: 2, column: 1 }, source_map.location(5));
+        assert_eq!(Location { line: 2, column: 3 }, source_map.location(100));
+        assert_eq!(
+            " --> line 2, column 1\n  |\n2 | çd\n  | ^",
+            source_map.render(Span::new(5, 5))
+        );
+        assert!(source_map.render(Span::new(5, 7)).ends_with("| ^^"));
+    }
+
+    #[test]
+    fn render_underlines_span() {
+        let source = "467..114..\n..35..6x3.\n";
+        let rendered = SourceMap::new(source).render(Span::new(17, 20));
+        assert_eq!(
+            " --> line 2, column 7\n  |\n2 | ..35..6x3.\n  |       ^^^",
+            rendered
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{Expected, ParseError, Parser};
+
+/// Types that can be parsed out of puzzle input. Usually implemented with
+/// `#[derive(FromPuzzleInput)]`, which describes the expected text with a
+/// template:
+///
+/// ```
+/// use tokenizer::FromPuzzleInput;
+///
+/// #[derive(FromPuzzleInput)]
+/// #[puzzle("Card {num}: {winning_nums: sep=' '} | {actual_nums: sep=' '}")]
+/// struct Card {
+///     num: usize,
+///     winning_nums: Vec<usize>,
+///     actual_nums: Vec<usize>,
+/// }
+///
+/// let cards = tokenizer::parse_lines::<Card>("Card 1: 41 48 | 83  6\nCard   2: 13 | 61\n").unwrap();
+/// assert_eq!(vec![83, 6], cards[0].actual_nums);
+/// assert_eq!(2, cards[1].num);
+/// ```
+pub trait FromPuzzleInput: Sized {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError>;
+
+    /// Parses the whole of `source` as a single value.
+    fn from_puzzle_input(source: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser::new(source);
+        let result = Self::parse(&mut parser)?;
+        parser.expect_end()?;
+        Ok(result)
+    }
+}
+
+/// Collections that can be parsed as one or more items with a separator
+/// between them. Used for template fields with a `sep` option.
+pub trait FromSeparated: Sized {
+    fn parse_separated(parser: &mut Parser<'_>, separator: &str) -> Result<Self, ParseError>;
+}
+
+/// Parses every line of `source` as a `T`. Errors point into the whole
+/// source, so they report the line and column of the problem.
+pub fn parse_lines<T: FromPuzzleInput>(source: &str) -> Result<Vec<T>, ParseError> {
+    let mut parser = Parser::new(source);
+    let mut results = Vec::new();
+
+    while parser.peek().is_some() {
+        results.push(T::parse(&mut parser)?);
+        parser.match_char('\r');
+        if parser.match_char('\n').is_none() && parser.peek().is_some() {
+            return Err(parser.error(Expected::Described("end of line")));
+        }
+    }
+
+    Ok(results)
+}
+
+impl<T: FromPuzzleInput> FromSeparated for Vec<T> {
+    fn parse_separated(parser: &mut Parser<'_>, separator: &str) -> Result<Self, ParseError> {
+        let mut results = vec![T::parse(parser)?];
+        loop {
+            let checkpoint = parser.checkpoint();
+            match __private::literal(parser, separator).and_then(|_| T::parse(parser)) {
+                Ok(result) => results.push(result),
+                Err(_) => {
+                    parser.rewind(checkpoint);
+                    return Ok(results);
+                }
+            }
+        }
+    }
+}
+
+impl FromPuzzleInput for usize {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        parser.expect_uint().map(|(_, value)| value)
+    }
+}
+
+impl FromPuzzleInput for isize {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        parser.expect_int().map(|(_, value)| value)
+    }
+}
+
+macro_rules! impl_from_puzzle_input_for_int {
+    ($via:ty, $description:literal, $($int:ty),+) => {
+        $(
+            impl FromPuzzleInput for $int {
+                fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+                    let checkpoint = parser.checkpoint();
+                    let value = <$via>::parse(parser)?;
+                    value.try_into().map_err(|_| {
+                        parser.rewind(checkpoint);
+                        parser.error(Expected::Described($description))
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_from_puzzle_input_for_int!(usize, "an unsigned integer in range", u8, u16, u32, u64);
+impl_from_puzzle_input_for_int!(isize, "an integer in range", i8, i16, i32, i64);
+
+/// Any single character other than a line break.
+impl FromPuzzleInput for char {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let span = parser.expect_char_if(|ch| ch != '\n' && ch != '\r', "a character")?;
+        Ok(parser.source[span.start()..]
+            .chars()
+            .next()
+            .expect("span to cover a character"))
+    }
+}
+
+/// A word made of one or more alphanumeric characters.
+impl FromPuzzleInput for String {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let span = parser.expect_char_while(|ch| ch.is_alphanumeric(), "a word")?;
+        Ok(parser.source[span.start()..span.end()].to_string())
+    }
+}
+
+/// Support functions for code generated by `#[derive(FromPuzzleInput)]`.
+#[doc(hidden)]
+pub mod __private {
+    use crate::{ParseError, Parser};
+
+    /// Matches template text. A run of spaces matches one or more spaces.
+    pub fn literal(parser: &mut Parser<'_>, literal: &str) -> Result<(), ParseError> {
+        let mut rest = literal;
+        while !rest.is_empty() {
+            let trimmed = rest.trim_start_matches(' ');
+            if trimmed.len() != rest.len() {
+                parser.expect_char_while(|ch| ch == ' ', "a space")?;
+                rest = trimmed;
+                continue;
+            }
+
+            let end = rest.find(' ').unwrap_or(rest.len());
+            parser.expect_str(&rest[..end])?;
+            rest = &rest[end..];
+        }
+
+        Ok(())
+    }
+
+    /// Tries one enum variant, rewinding and keeping track of the error that
+    /// got furthest if it does not match.
+    pub fn attempt<'i, T, F>(
+        parser: &mut Parser<'i>,
+        furthest: &mut Option<ParseError>,
+        mut variant: F,
+    ) -> Option<T>
+    where
+        F: FnMut(&mut Parser<'i>) -> Result<T, ParseError>,
+    {
+        let checkpoint = parser.checkpoint();
+        match variant(parser) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                parser.rewind(checkpoint);
+                if furthest
+                    .as_ref()
+                    .is_none_or(|furthest| furthest.span().start() < err.span().start())
+                {
+                    *furthest = Some(err);
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_lines, FromPuzzleInput, Location};
+
+    #[derive(Debug, PartialEq, FromPuzzleInput)]
+    enum Color {
+        #[puzzle("red")]
+        Red,
+        #[puzzle("green")]
+        Green,
+        #[puzzle("blue")]
+        Blue,
+    }
+
+    #[derive(Debug, PartialEq, FromPuzzleInput)]
+    #[puzzle("{0} {1}")]
+    struct Cubes(isize, Color);
+
+    #[derive(Debug, PartialEq, FromPuzzleInput)]
+    #[puzzle("Game {number}: {rounds: sep='; '}")]
+    struct Game {
+        number: u32,
+        rounds: Vec<Round>,
+    }
+
+    #[derive(Debug, PartialEq, FromPuzzleInput)]
+    #[puzzle("{cubes: sep=', '}")]
+    struct Round {
+        cubes: Vec<Cubes>,
+    }
+
+    #[test]
+    fn derive_struct_and_enum() {
+        let game = Game::from_puzzle_input("Game 3: 8 green, 6 blue; 1 red").unwrap();
+        assert_eq!(3, game.number);
+        assert_eq!(
+            vec![
+                Round {
+                    cubes: vec![Cubes(8, Color::Green), Cubes(6, Color::Blue)]
+                },
+                Round {
+                    cubes: vec![Cubes(1, Color::Red)]
+                }
+            ],
+            game.rounds
+        );
+    }
+
+    #[test]
+    fn derive_errors_point_into_source() {
+        let err = parse_lines::<Game>("Game 1: 1 red\nGame 2: 4 bleu\n").unwrap_err();
+        assert_eq!(
+            Location {
+                line: 2,
+                column: 13
+            },
+            err.location()
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt::{Display, Write};
+
+use crate::Span;
+
+/// Converts byte offsets into a source string into human readable line and
+/// column numbers, and renders spans as an underlined excerpt of the source.
+pub struct SourceMap<'s> {
+    source: &'s str,
+    line_starts: Vec<usize>,
+}
+
+/// A 1-based line and column. Columns are counted in characters rather than
+/// bytes so that they line up with what an editor shows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+impl<'s> SourceMap<'s> {
+    pub fn new(source: &'s str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(
+            source
+                .char_indices()
+                .filter(|(_, ch)| *ch == '\n')
+                .map(|(i, _)| i + 1),
+        );
+
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    pub fn source(&self) -> &'s str {
+        self.source
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The byte offset at which the given 1-based line starts.
+    pub fn line_start(&self, line: usize) -> usize {
+        self.line_starts[line - 1]
+    }
+
+    /// The text of the given 1-based line, without its line ending.
+    pub fn line(&self, line: usize) -> &'s str {
+        let start = self.line_start(line);
+        let end = self
+            .line_starts
+            .get(line)
+            .map(|next_start| next_start - 1)
+            .unwrap_or(self.source.len());
+
+        let text = &self.source[start..end];
+        text.strip_suffix('\r').unwrap_or(text)
+    }
+
+    /// The location of the character containing byte `offset`. Offsets
+    /// inside a multi-byte character are moved back to its first byte, and
+    /// offsets past the end to the end.
+    pub fn location(&self, offset: usize) -> Location {
+        let offset = self.source.floor_char_boundary(offset);
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        };
+        let column = self.source[self.line_start(line)..offset].chars().count() + 1;
+
+        Location { line, column }
+    }
+
+    /// Renders the line containing the start of `span` with the span
+    /// underlined, in the style of rustc diagnostics:
+    ///
+    /// ```text
+    ///  --> line 3, column 7
+    ///   |
+    /// 3 | ..35..6x3.
+    ///   |       ^
+    /// ```
+    ///
+    /// Spans that run past the end of the line are cut off there, and empty
+    /// spans are drawn as a single caret.
+    pub fn render(&self, span: Span) -> String {
+        let start = self.location(span.start());
+        let text = self.line(start.line);
+
+        let line_end = self.line_start(start.line) + text.len();
+        let span_start = self.source.floor_char_boundary(span.start()).min(line_end);
+        let end = self
+            .source
+            .floor_char_boundary(span.end())
+            .clamp(span_start, line_end);
+        let width = self.source[span_start..end].chars().count().max(1);
+
+        let gutter = start.line.to_string().len();
+        let mut result = String::new();
+        writeln!(result, "{:gutter$}--> {}", "", start).unwrap();
+        writeln!(result, "{:gutter$} |", "").unwrap();
+        writeln!(result, "{} | {}", start.line, text).unwrap();
+        write!(
+            result,
+            "{:gutter$} | {}{}",
+            "",
+            " ".repeat(start.column - 1),
+            "^".repeat(width)
+        )
+        .unwrap();
+
+        result
+    }
+}
@@ -0,0 +1,194 @@
+//! `#[derive(FromPuzzleInput)]` for the `tokenizer` crate. Use it through
+//! the re-export in `tokenizer` rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, LitStr};
+
+mod template;
+
+use template::Segment;
+
+/// Generates a `tokenizer::FromPuzzleInput` implementation from a
+/// `#[puzzle("...")]` template.
+///
+/// On a struct the template describes the whole value, with `{field}`
+/// placeholders for each field. A field can be given a separator with
+/// `{field: sep=', '}`, in which case it is parsed with
+/// `tokenizer::FromSeparated` (for example into a `Vec`). Tuple struct fields
+/// are referred to by index, as in `{0}`.
+///
+/// On an enum every variant carries its own template and the variants are
+/// tried in order until one matches.
+///
+/// In literal text a run of spaces matches one or more spaces in the input,
+/// so `"Card {num}:"` also accepts `"Card   1:"`.
+#[proc_macro_derive(FromPuzzleInput, attributes(puzzle))]
+pub fn derive_from_puzzle_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let template = template_attr(&input.attrs, input.ident.span())?;
+            parse_fields(quote!(Self), &data.fields, &template)?
+        }
+        Data::Enum(data) => {
+            if data.variants.is_empty() {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "FromPuzzleInput cannot be derived for an enum with no variants",
+                ));
+            }
+
+            let mut attempts = Vec::new();
+            for variant in data.variants.iter() {
+                let template = template_attr(&variant.attrs, variant.ident.span())?;
+                let ident = &variant.ident;
+                let body = parse_fields(quote!(Self::#ident), &variant.fields, &template)?;
+                attempts.push(quote! {
+                    if let Some(value) = ::tokenizer::__private::attempt(parser, &mut furthest, |parser| {
+                        #body
+                    }) {
+                        return Ok(value);
+                    }
+                });
+            }
+
+            quote! {
+                let mut furthest = None;
+                #(#attempts)*
+                Err(furthest.expect("at least one variant to have been tried"))
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "FromPuzzleInput cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::tokenizer::FromPuzzleInput for #name #ty_generics #where_clause {
+            fn parse(
+                parser: &mut ::tokenizer::Parser<'_>,
+            ) -> ::std::result::Result<Self, ::tokenizer::ParseError> {
+                #body
+            }
+        }
+    })
+}
+
+struct Template {
+    segments: Vec<Segment>,
+    span: Span,
+}
+
+fn template_attr(attrs: &[Attribute], item_span: Span) -> syn::Result<Template> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("puzzle"))
+        .ok_or_else(|| syn::Error::new(item_span, "missing #[puzzle(\"...\")] template"))?;
+
+    let template = attr.parse_args::<LitStr>()?;
+    let segments =
+        template::parse(&template.value()).map_err(|err| syn::Error::new(template.span(), err))?;
+
+    Ok(Template {
+        segments,
+        span: template.span(),
+    })
+}
+
+/// Generates statements that parse each segment of `template` in turn and
+/// then build the value with `constructor`.
+fn parse_fields(
+    constructor: TokenStream2,
+    fields: &Fields,
+    template: &Template,
+) -> syn::Result<TokenStream2> {
+    let field_names = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => index.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    let mut seen = vec![false; field_names.len()];
+    let mut statements = Vec::new();
+
+    for segment in template.segments.iter() {
+        match segment {
+            Segment::Literal(literal) => statements.push(quote! {
+                ::tokenizer::__private::literal(parser, #literal)?;
+            }),
+            Segment::Field { name, separator } => {
+                let index = field_names
+                    .iter()
+                    .position(|field_name| field_name == name)
+                    .ok_or_else(|| {
+                        syn::Error::new(template.span, format!("no field named `{}`", name))
+                    })?;
+
+                if seen[index] {
+                    return Err(syn::Error::new(
+                        template.span,
+                        format!("field `{}` appears more than once in the template", name),
+                    ));
+                }
+                seen[index] = true;
+
+                let variable = format_ident!("__field_{}", name);
+                let ty = &fields.iter().nth(index).expect("index to be in range").ty;
+                statements.push(match separator {
+                    None => quote! {
+                        let #variable = <#ty as ::tokenizer::FromPuzzleInput>::parse(parser)?;
+                    },
+                    Some(separator) => quote! {
+                        let #variable = <#ty as ::tokenizer::FromSeparated>::parse_separated(parser, #separator)?;
+                    },
+                });
+            }
+        }
+    }
+
+    if let Some(index) = seen.iter().position(|seen| !seen) {
+        return Err(syn::Error::new(
+            template.span,
+            format!(
+                "field `{}` is missing from the template",
+                field_names[index]
+            ),
+        ));
+    }
+
+    let variables = field_names
+        .iter()
+        .map(|name| format_ident!("__field_{}", name))
+        .collect::<Vec<_>>();
+
+    let construct = match fields {
+        Fields::Named(_) => {
+            let idents = fields.iter().map(|field| &field.ident);
+            quote!(#constructor { #(#idents: #variables),* })
+        }
+        Fields::Unnamed(_) => quote!(#constructor(#(#variables),*)),
+        Fields::Unit => quote!(#constructor),
+    };
+
+    Ok(quote! {
+        #(#statements)*
+        Ok(#construct)
+    })
+}
@@ -0,0 +1,136 @@
+/// One piece of a `#[puzzle("...")]` template.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Segment {
+    /// Text that must appear verbatim in the input. A run of spaces matches
+    /// one or more spaces.
+    Literal(String),
+    Field {
+        name: String,
+        separator: Option<String>,
+    },
+}
+
+/// Splits a template such as `"Card {num}: {nums: sep=' '}"` into literal
+/// and field segments. `{{` and `}}` stand for literal braces.
+pub fn parse(template: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut field = String::new();
+                let mut in_quotes = false;
+                loop {
+                    match chars.next() {
+                        None => return Err(format!("unclosed '{{' in template {:?}", template)),
+                        Some('\'') => {
+                            in_quotes = !in_quotes;
+                            field.push('\'');
+                        }
+                        Some('}') if !in_quotes => break,
+                        Some(ch) => field.push(ch),
+                    }
+                }
+
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                } else if matches!(segments.last(), Some(Segment::Field { .. })) {
+                    return Err(format!(
+                        "fields must be separated by literal text in template {:?}",
+                        template
+                    ));
+                }
+                segments.push(parse_field(&field)?);
+            }
+            '}' => return Err(format!("unmatched '}}' in template {:?}", template)),
+            ch => literal.push(ch),
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+}
+
+fn parse_field(field: &str) -> Result<Segment, String> {
+    let (name, options) = match field.split_once(':') {
+        None => (field, ""),
+        Some((name, options)) => (name, options),
+    };
+
+    let name = name.trim();
+    if name.is_empty() || !name.chars().all(|ch| ch.is_alphanumeric() || ch == '_') {
+        return Err(format!("invalid field name {:?}", name));
+    }
+
+    let mut separator = None;
+    let options = options.trim();
+    if !options.is_empty() {
+        let value = options
+            .strip_prefix("sep")
+            .map(str::trim_start)
+            .and_then(|rest| rest.strip_prefix('='))
+            .map(str::trim)
+            .ok_or_else(|| format!("unknown option {:?} for field {}", options, name))?;
+
+        let value = value
+            .strip_prefix('\'')
+            .and_then(|value| value.strip_suffix('\''))
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                format!(
+                    "separator for field {} must be a non-empty quoted string like sep=', '",
+                    name
+                )
+            })?;
+        separator = Some(value.to_string());
+    }
+
+    Ok(Segment::Field {
+        name: name.to_string(),
+        separator,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Segment};
+
+    #[test]
+    fn fields_and_literals() {
+        assert_eq!(
+            Ok(vec![
+                Segment::Literal("Card ".to_string()),
+                Segment::Field {
+                    name: "num".to_string(),
+                    separator: None
+                },
+                Segment::Literal(": {".to_string()),
+                Segment::Field {
+                    name: "nums".to_string(),
+                    separator: Some("} ".to_string())
+                },
+            ]),
+            parse("Card {num}: {{{nums: sep='} '}")
+        );
+    }
+
+    #[test]
+    fn rejects_adjacent_fields() {
+        assert!(parse("{a}{b}").is_err());
+        assert!(parse("{a: sep=}").is_err());
+        assert!(parse("{a: width=3}").is_err());
+    }
+}
@@ -0,0 +1,71 @@
+//! Generates a test for every example under `examples/`. See
+//! `src/examples.rs` for the layout.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner to live inside the workspace")
+        .join("examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let mut inputs = Vec::new();
+    for year_dir in subdirectories(&examples_dir) {
+        for day_dir in subdirectories(&year_dir) {
+            let files = fs::read_dir(&day_dir).expect("example directory to be readable");
+            for file in files {
+                let file = file.expect("example directory to be readable").path();
+                if file.extension().is_some_and(|extension| extension == "txt") {
+                    inputs.push(file);
+                }
+            }
+        }
+    }
+    inputs.sort();
+
+    let mut tests = String::new();
+    for input in inputs {
+        let day_dir = dir_name(input.parent());
+        let year_dir = dir_name(input.parent().and_then(Path::parent));
+        let name = input
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .expect("example to have a file name");
+        let test_name = format!("y{}_{}_example_{}", year_dir, day_dir, name)
+            .chars()
+            .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+            .collect::<String>();
+
+        tests.push_str(&format!(
+            "#[test]\nfn {}() {{\n    check_example({:?});\n}}\n\n",
+            test_name,
+            input.display().to_string()
+        ));
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR to be set by cargo");
+    fs::write(Path::new(&out_dir).join("examples.rs"), tests)
+        .expect("generated tests to be writable");
+}
+
+fn dir_name(dir: Option<&Path>) -> &str {
+    dir.and_then(Path::file_name)
+        .and_then(|dir| dir.to_str())
+        .expect("example to be in a <year>/day<NN> directory")
+}
+
+/// The directories directly inside `dir`, or none if it doesn't exist.
+fn subdirectories(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .map(|entry| entry.expect("examples directory to be readable").path())
+        .filter(|path| path.is_dir())
+        .collect()
+}
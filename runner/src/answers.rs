@@ -0,0 +1,148 @@
+//! Known answers, stored by the SHA-256 of the input they belong to so that
+//! answers for several people's inputs can live side by side. The store is a
+//! JSON file, `answers.json` at the root of the repository by default, and
+//! holds no puzzle input, so it can be committed.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `provider::checksum` of the exact input the answer is for.
+    pub input: String,
+    pub answer: String,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerStore {
+    pub answers: Vec<Answer>,
+}
+
+impl AnswerStore {
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("runner to live inside the workspace")
+            .join("answers.json")
+    }
+
+    /// Reads a store, or returns an empty one if the file doesn't exist.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|answer| {
+                (answer.year, answer.day, answer.part) == (year, day, part) && answer.input == input
+            })
+            .map(|answer| answer.answer.as_str())
+    }
+
+    /// Stores `answer`, replacing any earlier answer for the same input and
+    /// part. Answers are kept sorted so the file diffs cleanly.
+    pub fn insert(&mut self, answer: Answer) {
+        self.answers.retain(|existing| {
+            (existing.year, existing.day, existing.part, &existing.input)
+                != (answer.year, answer.day, answer.part, &answer.input)
+        });
+        self.answers.push(answer);
+        self.answers.sort_by(|a, b| {
+            (a.year, a.day, &a.input, a.part).cmp(&(b.year, b.day, &b.input, b.part))
+        });
+    }
+}
+
+/// How a part's answer compares with the store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+    Panicked,
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&str>, actual: Option<&str>) -> Self {
+        match (expected, actual) {
+            (_, None) => Self::Panicked,
+            (None, Some(_)) => Self::Unknown,
+            (Some(expected), Some(actual)) if expected == actual => Self::Pass,
+            (Some(expected), Some(_)) => Self::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Fail { .. } | Self::Panicked)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Self::Unknown => write!(f, "unknown"),
+            Self::Panicked => write!(f, "PANICKED"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, AnswerStore, Verdict};
+
+    #[test]
+    fn store_lookup_and_replace() {
+        let answer = |part, answer: &str| Answer {
+            year: 2023,
+            day: 4,
+            part,
+            input: "0123456789abcdef".to_string(),
+            answer: answer.to_string(),
+        };
+
+        let mut store = AnswerStore::default();
+        store.insert(answer(2, "30"));
+        store.insert(answer(1, "12"));
+        store.insert(answer(1, "13"));
+        assert_eq!(2, store.answers.len());
+        assert_eq!(Some("13"), store.get(2023, 4, 1, "0123456789abcdef"));
+        assert_eq!(None, store.get(2023, 4, 1, "fedcba9876543210"));
+
+        let json = serde_json::to_string(&store).unwrap();
+        assert_eq!(store, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(Verdict::Pass, Verdict::new(Some("13"), Some("13")));
+        assert_eq!(Verdict::Unknown, Verdict::new(None, Some("13")));
+        assert_eq!(Verdict::Panicked, Verdict::new(Some("13"), None));
+        assert_eq!(
+            "FAIL (expected 13)",
+            Verdict::new(Some("13"), Some("12")).to_string()
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! Benchmarking for the solutions, in the style of criterion: each function is
+//! warmed up, then timed over a number of samples, each made of enough
+//! iterations to be measured reliably. Results are saved as JSON so that the
+//! next run can say what got faster or slower.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// How long to spend on each benchmark.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchConfig {
+    /// Time spent running the function before any samples are taken.
+    pub warmup: Duration,
+    /// Roughly the total time spent taking samples.
+    pub measurement: Duration,
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(300),
+            measurement: Duration::from_secs(1),
+            samples: 50,
+        }
+    }
+}
+
+/// Summary statistics of a benchmark, all in nanoseconds per iteration.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub iterations_per_sample: u64,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub p95_ns: f64,
+    pub min_ns: f64,
+    pub max_ns: f64,
+}
+
+impl Stats {
+    /// Summarises per-iteration sample times. Panics if there are no samples.
+    pub fn from_samples(samples: &[f64], iterations_per_sample: u64) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        Self {
+            samples: sorted.len(),
+            iterations_per_sample,
+            mean_ns: sorted.iter().sum::<f64>() / sorted.len() as f64,
+            median_ns: percentile(&sorted, 50.0),
+            p95_ns: percentile(&sorted, 95.0),
+            min_ns: sorted[0],
+            max_ns: sorted[sorted.len() - 1],
+        }
+    }
+
+    /// Bytes of input handled per second, based on the median. `None` if the
+    /// median isn't positive, as for a part too quick to measure.
+    pub fn throughput(&self, input_bytes: usize) -> Option<f64> {
+        if self.median_ns <= 0.0 || self.median_ns.is_nan() {
+            return None;
+        }
+
+        Some(input_bytes as f64 / (self.median_ns / 1e9))
+    }
+}
+
+/// The `pct`th percentile of `sorted`, interpolating linearly between the
+/// two nearest samples.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    let rank = pct / 100.0 * (sorted.len() - 1) as f64;
+    let below = rank.floor() as usize;
+    let above = rank.ceil() as usize;
+    sorted[below] + (sorted[above] - sorted[below]) * (rank - below as f64)
+}
+
+/// Benchmarks `f`. Its return value is passed through `black_box` so the
+/// work can't be optimised away.
+pub fn bench<T, F: FnMut() -> T>(config: &BenchConfig, mut f: F) -> Stats {
+    let warmup_start = Instant::now();
+    let mut warmup_iterations = 0u64;
+    while warmup_iterations == 0 || warmup_start.elapsed() < config.warmup {
+        std::hint::black_box(f());
+        warmup_iterations += 1;
+    }
+    let per_iteration = warmup_start.elapsed().as_secs_f64() / warmup_iterations as f64;
+
+    let sample_time = config.measurement.as_secs_f64() / config.samples as f64;
+    let iterations = ((sample_time / per_iteration) as u64).max(1);
+
+    let samples = (0..config.samples)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                std::hint::black_box(f());
+            }
+            start.elapsed().as_nanos() as f64 / iterations as f64
+        })
+        .collect::<Vec<_>>();
+
+    Stats::from_samples(&samples, iterations)
+}
+
+/// What part of a day a benchmark times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub fn for_part(part: u8) -> Self {
+        match part {
+            1 => Self::Part1,
+            2 => Self::Part2,
+            _ => panic!("part must be 1 or 2, not {}", part),
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part1 => write!(f, "part 1"),
+            Self::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BenchRecord {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub input_bytes: usize,
+    pub stats: Stats,
+}
+
+/// The contents of a results file.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchResults {
+    pub records: Vec<BenchRecord>,
+}
+
+impl BenchResults {
+    /// Where results are kept unless `--save` says otherwise.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("runner to live inside the workspace")
+            .join("bench.json")
+    }
+
+    /// Reads a results file, or returns empty results if it doesn't exist.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn find(&self, year: u16, day: u8, stage: Stage) -> Option<&BenchRecord> {
+        self.records
+            .iter()
+            .find(|record| (record.year, record.day, record.stage) == (year, day, stage))
+    }
+
+    /// Adds `record`, replacing any earlier record for the same day and stage.
+    pub fn insert(&mut self, record: BenchRecord) {
+        self.records.retain(|existing| {
+            (existing.year, existing.day, existing.stage) != (record.year, record.day, record.stage)
+        });
+        self.records.push(record);
+        self.records
+            .sort_by_key(|record| (record.year, record.day, record.stage as u8));
+    }
+}
+
+/// How a benchmark's median compares with an earlier run's.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Change {
+    Improved(f64),
+    Regressed(f64),
+    /// Within the noise threshold.
+    Unchanged(f64),
+}
+
+/// Changes in the median smaller than this fraction are treated as noise.
+pub const NOISE_THRESHOLD: f64 = 0.05;
+
+impl Change {
+    /// `None` if the old median isn't positive, as for a part too quick to
+    /// measure or a hand-edited file, since there is nothing to compare with.
+    pub fn between(old: &Stats, new: &Stats) -> Option<Self> {
+        if old.median_ns <= 0.0 || old.median_ns.is_nan() {
+            return None;
+        }
+
+        let ratio = new.median_ns / old.median_ns - 1.0;
+        Some(if ratio.abs() <= NOISE_THRESHOLD {
+            Self::Unchanged(ratio)
+        } else if ratio < 0.0 {
+            Self::Improved(ratio)
+        } else {
+            Self::Regressed(ratio)
+        })
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Improved(ratio) => write!(f, "{:+.1}% improved", ratio * 100.0),
+            Self::Regressed(ratio) => write!(f, "{:+.1}% regressed", ratio * 100.0),
+            Self::Unchanged(ratio) => write!(f, "{:+.1}% no change", ratio * 100.0),
+        }
+    }
+}
+
+/// Formats a throughput in bytes per second, for example `12.3 MB/s`.
+pub fn format_throughput(bytes_per_second: f64) -> String {
+    if bytes_per_second < 1e3 {
+        format!("{:.0} B/s", bytes_per_second)
+    } else if bytes_per_second < 1e6 {
+        format!("{:.1} kB/s", bytes_per_second / 1e3)
+    } else if bytes_per_second < 1e9 {
+        format!("{:.1} MB/s", bytes_per_second / 1e6)
+    } else {
+        format!("{:.1} GB/s", bytes_per_second / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BenchRecord, BenchResults, Change, Stage, Stats};
+
+    #[test]
+    fn statistics() {
+        let samples = (1..=20).rev().map(|n| n as f64).collect::<Vec<_>>();
+        let stats = Stats::from_samples(&samples, 3);
+        assert_eq!(10.5, stats.mean_ns);
+        assert_eq!(10.5, stats.median_ns);
+        assert_eq!(19.05, stats.p95_ns);
+        assert_eq!(1.0, stats.min_ns);
+        assert_eq!(20.0, stats.max_ns);
+        assert_eq!(Some(2e8), Stats::from_samples(&[5.0], 1).throughput(1));
+        assert_eq!(None, Stats::from_samples(&[0.0], 1).throughput(1));
+    }
+
+    #[test]
+    fn changes_outside_the_noise_threshold() {
+        let stats = |median_ns| Stats::from_samples(&[median_ns], 1);
+        assert!(matches!(
+            Change::between(&stats(100.0), &stats(80.0)),
+            Some(Change::Improved(_))
+        ));
+        assert!(matches!(
+            Change::between(&stats(100.0), &stats(103.0)),
+            Some(Change::Unchanged(_))
+        ));
+        assert_eq!(
+            "+50.0% regressed",
+            Change::between(&stats(100.0), &stats(150.0))
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(None, Change::between(&stats(0.0), &stats(150.0)));
+        assert_eq!(None, Change::between(&stats(0.0), &stats(0.0)));
+    }
+
+    #[test]
+    fn results_round_trip_and_replace() {
+        let record = |day, median_ns| BenchRecord {
+            year: 2023,
+            day,
+            stage: Stage::Part1,
+            input_bytes: 10,
+            stats: Stats::from_samples(&[median_ns], 1),
+        };
+
+        let mut results = BenchResults::default();
+        results.insert(record(5, 1.0));
+        results.insert(record(2, 1.0));
+        results.insert(record(5, 2.0));
+        assert_eq!(
+            vec![2, 5],
+            results.records.iter().map(|r| r.day).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            2.0,
+            results.find(2023, 5, Stage::Part1).unwrap().stats.median_ns
+        );
+
+        let json = serde_json::to_string(&results).unwrap();
+        assert!(json.contains("\"stage\":\"part1\""));
+        assert_eq!(results, serde_json::from_str(&json).unwrap());
+    }
+}
@@ -0,0 +1,175 @@
+//! What the submission log says about an answer before it is submitted. Every
+//! guess that was too high or too low narrows down where the answer can be,
+//! so a newly computed answer outside those bounds is known to be wrong
+//! without asking the server.
+
+use std::fmt::Display;
+
+use crate::submit::{Reply, Submission};
+
+/// Everything known about one part's answer.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Bounds {
+    /// The highest guess that was too low; the answer is above it.
+    pub above: Option<i128>,
+    /// The lowest guess that was too high; the answer is below it.
+    pub below: Option<i128>,
+    /// Every rejected guess, in the order they were submitted.
+    pub wrong: Vec<String>,
+    pub correct: Option<String>,
+}
+
+impl Bounds {
+    pub fn from_history<'a, I: IntoIterator<Item = &'a Submission>>(history: I) -> Self {
+        let mut bounds = Self::default();
+        for submission in history {
+            let guess = submission.answer.parse::<i128>().ok();
+            match submission.reply {
+                Reply::Correct => bounds.correct = Some(submission.answer.clone()),
+                Reply::TooHigh => bounds.below = min_option(bounds.below, guess),
+                Reply::TooLow => bounds.above = max_option(bounds.above, guess),
+                _ => {}
+            }
+            if submission.reply != Reply::Correct && submission.reply.is_judgement() {
+                bounds.wrong.push(submission.answer.clone());
+            }
+        }
+
+        bounds
+    }
+
+    /// Why `answer` can't be right, if anything known rules it out.
+    pub fn check(&self, answer: &str) -> Option<Flag> {
+        if let Some(correct) = &self.correct {
+            return (correct != answer).then(|| Flag::NotCorrect(correct.clone()));
+        }
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            return Some(Flag::Rejected);
+        }
+
+        let answer = answer.parse::<i128>().ok()?;
+        match (self.above, self.below) {
+            (Some(above), _) if answer <= above => Some(Flag::TooLow(above)),
+            (_, Some(below)) if answer >= below => Some(Flag::TooHigh(below)),
+            _ => None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(correct) = &self.correct {
+            return write!(f, "solved with {}", correct);
+        }
+
+        match (self.above, self.below) {
+            (Some(above), Some(below)) => {
+                write!(f, "the answer is above {} and below {}", above, below)?
+            }
+            (Some(above), None) => write!(f, "the answer is above {}", above)?,
+            (None, Some(below)) => write!(f, "the answer is below {}", below)?,
+            (None, None) => write!(f, "nothing is known about the answer")?,
+        }
+        match self.wrong.len() {
+            1 => write!(f, ", 1 wrong guess"),
+            wrong => write!(f, ", {} wrong guesses", wrong),
+        }
+    }
+}
+
+/// Why an answer is known to be wrong.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Flag {
+    /// The part was solved with a different answer.
+    NotCorrect(String),
+    /// The same answer was submitted and rejected.
+    Rejected,
+    /// The answer is no higher than a guess that was too low.
+    TooLow(i128),
+    /// The answer is no lower than a guess that was too high.
+    TooHigh(i128),
+}
+
+impl Display for Flag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotCorrect(correct) => write!(f, "WRONG (solved with {})", correct),
+            Self::Rejected => write!(f, "WRONG (already rejected)"),
+            Self::TooLow(above) => write!(f, "TOO LOW ({} was too low)", above),
+            Self::TooHigh(below) => write!(f, "TOO HIGH ({} was too high)", below),
+        }
+    }
+}
+
+fn min_option(current: Option<i128>, new: Option<i128>) -> Option<i128> {
+    match (current, new) {
+        (Some(current), Some(new)) => Some(current.min(new)),
+        (current, new) => current.or(new),
+    }
+}
+
+fn max_option(current: Option<i128>, new: Option<i128>) -> Option<i128> {
+    match (current, new) {
+        (Some(current), Some(new)) => Some(current.max(new)),
+        (current, new) => current.or(new),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, Flag};
+    use crate::submit::{Reply, Submission};
+
+    fn submission(answer: &str, reply: Reply) -> Submission {
+        Submission {
+            year: 2023,
+            day: 5,
+            part: 1,
+            answer: answer.to_string(),
+            reply,
+            at: 0,
+        }
+    }
+
+    #[test]
+    fn narrows_down_the_answer() {
+        let history = [
+            submission("100", Reply::TooLow),
+            submission("500", Reply::TooHigh),
+            submission("300", Reply::TooHigh),
+            submission("150", Reply::TooLow),
+            submission("200", Reply::Incorrect),
+        ];
+        let bounds = Bounds::from_history(&history);
+        assert_eq!(Some(150), bounds.above);
+        assert_eq!(Some(300), bounds.below);
+        assert_eq!(
+            "the answer is above 150 and below 300, 5 wrong guesses",
+            bounds.to_string()
+        );
+
+        assert_eq!(Some(Flag::TooLow(150)), bounds.check("149"));
+        assert_eq!(Some(Flag::TooLow(150)), bounds.check("-7"));
+        assert_eq!(Some(Flag::TooHigh(300)), bounds.check("301"));
+        assert_eq!(Some(Flag::Rejected), bounds.check("200"));
+        assert_eq!(None, bounds.check("151"));
+        assert_eq!(None, bounds.check("abc"));
+    }
+
+    #[test]
+    fn correct_answer_overrides_bounds() {
+        let history = [
+            submission("10", Reply::TooLow),
+            submission("42", Reply::Correct),
+        ];
+        let bounds = Bounds::from_history(&history);
+        assert_eq!(None, bounds.check("42"));
+        assert_eq!(Some(Flag::NotCorrect("42".to_string())), bounds.check("43"));
+        assert_eq!("solved with 42", bounds.to_string());
+        assert!(Bounds::from_history(&[]).is_empty());
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use aoc::registry::{self, Day};
+
+/// Command line arguments split into positional arguments, `--flag value`
+/// options and `--switch` flags that take no value. Options may also be
+/// written as `--flag=value`.
+pub struct Args {
+    pub positional: Vec<String>,
+    options: HashMap<&'static str, String>,
+    switches: Vec<&'static str>,
+}
+
+impl Args {
+    /// Parses `args`, accepting only the options in `flags` and the switches
+    /// in `switches`.
+    pub fn parse<I: IntoIterator<Item = String>>(
+        args: I,
+        flags: &[&'static str],
+        switches: &[&'static str],
+    ) -> Result<Self, String> {
+        let mut parsed = Self {
+            positional: Vec::new(),
+            options: HashMap::new(),
+            switches: Vec::new(),
+        };
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if !arg.starts_with("--") {
+                parsed.positional.push(arg);
+                continue;
+            }
+
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+
+            if let Some(flag) = flags.iter().find(|flag| **flag == name) {
+                let value = match inline_value {
+                    Some(value) => value,
+                    None => args
+                        .next()
+                        .ok_or_else(|| format!("{} needs a value", flag))?,
+                };
+                parsed.options.insert(flag, value);
+            } else if let Some(switch) = switches.iter().find(|switch| **switch == name) {
+                if inline_value.is_some() {
+                    return Err(format!("{} does not take a value", switch));
+                }
+                parsed.switches.push(switch);
+            } else {
+                return Err(format!("unknown option {}", name));
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub fn option(&self, flag: &str) -> Option<&str> {
+        self.options.get(flag).map(String::as_str)
+    }
+
+    pub fn switch(&self, switch: &str) -> bool {
+        self.switches.contains(&switch)
+    }
+}
+
+/// Which days a command should act on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Selection {
+    /// Every day of every year.
+    Everything,
+    Year(u16),
+    Day(u16, u8),
+}
+
+impl Selection {
+    /// Parses `[<year>] <day|all>`, or a year on its own for every day of
+    /// that year. Without a year, `default_year` is used.
+    pub fn parse(args: &[String], default_year: u16) -> Result<Self, String> {
+        match args {
+            [arg] if arg == "all" => Ok(Self::Year(default_year)),
+            [arg] => match parse_year(arg) {
+                Some(year) => Ok(Self::Year(year)),
+                None => Ok(Self::Day(default_year, parse_day(arg)?)),
+            },
+            [year, day] => {
+                let year = parse_year(year)
+                    .ok_or_else(|| format!("expected a year such as 2023, not {:?}", year))?;
+                if day == "all" {
+                    Ok(Self::Year(year))
+                } else {
+                    Ok(Self::Day(year, parse_day(day)?))
+                }
+            }
+            [] => Err("expected a day".to_string()),
+            _ => Err("expected at most a year and a day".to_string()),
+        }
+    }
+
+    pub fn days(&self) -> Vec<&'static Day> {
+        match *self {
+            Self::Everything => registry::DAYS.iter().collect(),
+            Self::Year(year) => registry::days_of(year).collect(),
+            Self::Day(year, day) => registry::find(year, day).into_iter().collect(),
+        }
+    }
+}
+
+fn parse_year(arg: &str) -> Option<u16> {
+    arg.parse().ok().filter(|year| *year >= 2015)
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    match arg.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!(
+            "expected a day from 1 to 25 or \"all\", not {:?}",
+            arg
+        )),
+    }
+}
+
+pub fn parse_part(arg: &str) -> Result<u8, String> {
+    match arg {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("part must be 1 or 2, not {:?}", arg)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Args, Selection};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn options_and_positionals() {
+        let parsed = Args::parse(
+            args(&["5", "--part", "2", "--input-dir=/tmp/in", "--record"]),
+            &["--part", "--input-dir"],
+            &["--record"],
+        )
+        .unwrap();
+        assert_eq!(vec!["5".to_string()], parsed.positional);
+        assert_eq!(Some("2"), parsed.option("--part"));
+        assert_eq!(Some("/tmp/in"), parsed.option("--input-dir"));
+        assert!(parsed.switch("--record"));
+        assert!(!parsed.switch("--other"));
+    }
+
+    #[test]
+    fn rejects_unknown_and_incomplete_options() {
+        assert!(Args::parse(args(&["--bogus"]), &[], &[]).is_err());
+        assert!(Args::parse(args(&["--part"]), &["--part"], &[]).is_err());
+        assert!(Args::parse(args(&["--record=yes"]), &[], &["--record"]).is_err());
+    }
+
+    #[test]
+    fn selection() {
+        let parse = |selection: &[&str]| Selection::parse(&args(selection), 2023);
+        assert_eq!(Ok(Selection::Year(2023)), parse(&["all"]));
+        assert_eq!(Ok(Selection::Day(2023, 5)), parse(&["5"]));
+        assert_eq!(Ok(Selection::Year(2024)), parse(&["2024"]));
+        assert_eq!(Ok(Selection::Year(2022)), parse(&["2022", "all"]));
+        assert_eq!(Ok(Selection::Day(2024, 5)), parse(&["2024", "5"]));
+        assert!(parse(&["26"]).is_err());
+        assert!(parse(&["5", "5"]).is_err());
+        assert!(parse(&[]).is_err());
+    }
+}
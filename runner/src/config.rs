@@ -0,0 +1,88 @@
+//! Personal settings, read from `aoc.toml` at the root of the repository or
+//! from the file named by the `AOC_CONFIG` environment variable:
+//!
+//! ```toml
+//! # the value of the `session` cookie set by adventofcode.com once logged in
+//! session = "53616c7465645f5f..."
+//! # where inputs are downloaded from; only worth changing for testing
+//! base_url = "https://adventofcode.com"
+//! ```
+//!
+//! The session token is as good as a password, so `aoc.toml` is ignored by
+//! git. Without it, inputs have to be downloaded by hand.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// `AOC_CONFIG` if it is set, otherwise `aoc.toml` at the root of the
+    /// repository.
+    pub fn default_path() -> PathBuf {
+        match std::env::var_os(CONFIG_VAR) {
+            Some(path) => PathBuf::from(path),
+            None => Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .expect("runner to live inside the workspace")
+                .join("aoc.toml"),
+        }
+    }
+
+    /// Reads a config file, or returns the defaults if it doesn't exist.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                toml::from_str(&text).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL)
+            .trim_end_matches('/')
+    }
+
+    /// The session token, unless it is missing or blank.
+    pub fn session(&self) -> Option<&str> {
+        self.session
+            .as_deref()
+            .map(str::trim)
+            .filter(|session| !session.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, DEFAULT_BASE_URL};
+
+    #[test]
+    fn defaults_and_overrides() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(None, config.session());
+        assert_eq!(DEFAULT_BASE_URL, config.base_url());
+
+        let config: Config =
+            toml::from_str("session = \" abc \"\nbase_url = \"http://127.0.0.1:8080/\"").unwrap();
+        assert_eq!(Some("abc"), config.session());
+        assert_eq!("http://127.0.0.1:8080", config.base_url());
+
+        assert!(toml::from_str::<Config>("sesion = \"abc\"").is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! Worked examples from the puzzle descriptions, checked by the tests.
+//!
+//! Examples live in `examples/<year>/day<NN>/` at the root of the repository. Each
+//! one is a `<name>.txt` input with a `<name>.expected` file next to it that
+//! gives the answer for one or both parts:
+//!
+//! ```text
+//! # comments and blank lines are ignored
+//! part1: 35
+//! part2: 46
+//! ```
+//!
+//! A part that isn't listed isn't checked, which is how an example that only
+//! applies to one part is written. The runner's build script generates a test
+//! for every example it finds, named after the year, the day and the file,
+//! such as `y2023_day05_example_1`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::registry;
+
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub input_path: PathBuf,
+    /// The expected answer for part 1 and part 2, if given.
+    pub expected: [Option<String>; 2],
+}
+
+/// The `examples` directory at the root of the repository.
+pub fn default_examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner to live inside the workspace")
+        .join("examples")
+}
+
+pub fn expected_path(input_path: &Path) -> PathBuf {
+    input_path.with_extension("expected")
+}
+
+/// Reads the answers out of an expectations file.
+pub fn parse_expected(text: &str) -> Result<[Option<String>; 2], String> {
+    let mut expected = [None, None];
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, answer) = line
+            .split_once(':')
+            .ok_or_else(|| format!("line {}: expected `part1: <answer>`", i + 1))?;
+        let slot = match key.trim() {
+            "part1" => &mut expected[0],
+            "part2" => &mut expected[1],
+            key => return Err(format!("line {}: unknown key {:?}", i + 1, key)),
+        };
+
+        if slot.is_some() {
+            return Err(format!("line {}: {} is given twice", i + 1, key.trim()));
+        }
+        *slot = Some(answer.trim().to_string());
+    }
+
+    Ok(expected)
+}
+
+impl Example {
+    /// Loads the example whose input is at `input_path`, which must be inside
+    /// a `<year>/day<NN>` directory.
+    pub fn load(input_path: &Path) -> Result<Self, String> {
+        let day_dir = input_path.parent();
+        let year_dir = day_dir.and_then(Path::parent);
+        let (year, day) = dir_name(year_dir)
+            .and_then(|year| year.parse().ok())
+            .zip(
+                dir_name(day_dir)
+                    .and_then(|dir| dir.strip_prefix("day"))
+                    .and_then(|day| day.parse().ok()),
+            )
+            .ok_or_else(|| {
+                format!(
+                    "{} is not inside a <year>/day<NN> directory",
+                    input_path.display()
+                )
+            })?;
+        let name = input_path
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| format!("{} has no file name", input_path.display()))?
+            .to_string();
+
+        let expected_path = expected_path(input_path);
+        let expected = fs::read_to_string(&expected_path)
+            .map_err(|err| format!("failed to read {}: {}", expected_path.display(), err))
+            .and_then(|text| {
+                parse_expected(&text).map_err(|err| format!("{}: {}", expected_path.display(), err))
+            })?;
+
+        Ok(Self {
+            year,
+            day,
+            name,
+            input_path: input_path.to_path_buf(),
+            expected,
+        })
+    }
+
+    /// Runs the day's solution on the example and compares every part that
+    /// has an expected answer. Returns a description of each mismatch.
+    pub fn check(&self) -> Result<(), String> {
+        let day = registry::find(self.year, self.day)
+            .ok_or(format!("{} day {} has no solution", self.year, self.day))?;
+        let data = fs::read_to_string(&self.input_path)
+            .map_err(|err| format!("failed to read {}: {}", self.input_path.display(), err))?;
+
+        let input = (day.parse)(&data);
+        let mismatches = (1..=2)
+            .zip(self.expected.iter())
+            .filter_map(|(part, expected)| {
+                let expected = expected.as_ref()?;
+                let actual = day.part(part)(input.as_ref());
+                (actual != *expected).then(|| {
+                    format!(
+                        "{} day {} example {} part {}: expected {}, got {}",
+                        self.year, self.day, self.name, part, expected, actual
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches.join("\n"))
+        }
+    }
+}
+
+fn dir_name(dir: Option<&Path>) -> Option<&str> {
+    dir?.file_name()?.to_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_expected;
+
+    #[test]
+    fn expectations() {
+        assert_eq!(
+            Ok([Some("35".to_string()), None]),
+            parse_expected("# from the puzzle\npart1: 35\n\n")
+        );
+        assert_eq!(
+            Ok([None, Some("a b".to_string())]),
+            parse_expected("part2:  a b ")
+        );
+        assert!(parse_expected("part3: 1").is_err());
+        assert!(parse_expected("part1: 1\npart1: 2").is_err());
+        assert!(parse_expected("35").is_err());
+    }
+}
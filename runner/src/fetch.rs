@@ -0,0 +1,158 @@
+//! Talking to adventofcode.com. `Fetch` is what `InputProvider` calls when an
+//! input isn't cached; `HttpFetch` implements it, and `submit::Submit`, against
+//! adventofcode.com or any server with the same URLs.
+
+use std::{fmt::Display, time::Duration};
+
+use crate::config::Config;
+
+/// Sent with every request, as adventofcode.com asks automated tools to do.
+const USER_AGENT: &str = "github.com/mr-adult/advent-of-code runner";
+
+pub trait Fetch {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// The server answered with an error status, such as 404 before the
+    /// puzzle unlocks or 400 when the session token has expired.
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    /// The server couldn't be reached or the response couldn't be read.
+    Transport { url: String, message: String },
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Status { url, status, body } => {
+                write!(f, "{} answered {}", url, status)?;
+                match body.trim() {
+                    "" => Ok(()),
+                    body => write!(f, ": {}", body),
+                }
+            }
+            Self::Transport { url, message } => write!(f, "{}: {}", url, message),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+pub struct HttpFetch {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpFetch {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// A backend for the configured server, or `None` if there is no session
+    /// token to authenticate with.
+    pub fn from_config(config: &Config) -> Option<Self> {
+        config
+            .session()
+            .map(|session| Self::new(config.base_url(), session))
+    }
+
+    /// The full URL of `path`, which starts with a `/`.
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn get(&self, url: String) -> Result<String, FetchError> {
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(url, response)
+    }
+
+    pub(crate) fn post_form(
+        &self,
+        url: String,
+        form: &[(&str, &str)],
+    ) -> Result<String, FetchError> {
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form);
+        read_response(url, response)
+    }
+}
+
+impl Fetch for HttpFetch {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        self.get(self.url(&format!("/{}/day/{}/input", year, day)))
+    }
+}
+
+fn read_response(
+    url: String,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, FetchError> {
+    match response {
+        Ok(response) => response.into_string().map_err(|err| FetchError::Transport {
+            url,
+            message: err.to_string(),
+        }),
+        Err(ureq::Error::Status(status, response)) => Err(FetchError::Status {
+            url,
+            status,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(ureq::Error::Transport(transport)) => Err(FetchError::Transport {
+            url,
+            message: transport.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fetch, FetchError, HttpFetch};
+    use crate::stub::StubServer;
+
+    #[test]
+    fn fetches_with_session_cookie() {
+        let server = StubServer::start(|_| (200, "1 2\n3 4\n".to_string()));
+        let fetch = HttpFetch::new(&server.url(), "secret");
+
+        assert_eq!("1 2\n3 4\n", fetch.fetch_input(2023, 5).unwrap());
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("GET", requests[0].method);
+        assert_eq!("/2023/day/5/input", requests[0].path);
+        assert_eq!(Some("session=secret"), requests[0].header("cookie"));
+        assert!(requests[0].header("user-agent").is_some());
+    }
+
+    #[test]
+    fn reports_error_status() {
+        let server = StubServer::start(|_| (404, "Not found".to_string()));
+        let fetch = HttpFetch::new(&server.url(), "secret");
+
+        let err = fetch.fetch_input(2023, 25).unwrap_err();
+        assert!(matches!(err, FetchError::Status { status: 404, .. }));
+        assert!(err
+            .to_string()
+            .ends_with("/2023/day/25/input answered 404: Not found"));
+    }
+}
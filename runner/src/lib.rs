@@ -0,0 +1,21 @@
+//! The pieces of the `aoc` runner that other tools can reuse: the table of
+//! every day's solution, the code that runs a single day, where inputs come
+//! from and how missing ones are downloaded, the store of known answers,
+//! answer submission and what earlier guesses say about an answer, the
+//! benchmarking harness, the examples the tests check and the generator for
+//! new days.
+
+pub mod answers;
+pub mod bench;
+pub mod bounds;
+pub mod config;
+pub mod examples;
+pub mod fetch;
+pub mod provider;
+pub mod registry;
+pub mod run;
+pub mod scaffold;
+#[cfg(test)]
+mod stub;
+pub mod submit;
+pub mod table;
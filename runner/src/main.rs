@@ -0,0 +1,776 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc::{
+    answers::{Answer, AnswerStore, Verdict},
+    bench::{bench, format_throughput, BenchConfig, BenchRecord, BenchResults, Change, Stage},
+    bounds::Bounds,
+    config::Config,
+    fetch::{Fetch, HttpFetch},
+    provider::{checksum, InputProvider},
+    registry::{self, Day},
+    run::{run_day, DayOutcome},
+    scaffold,
+    submit::{submit, Reply, SubmissionLog},
+    table::{format_duration, format_timestamp, Table},
+};
+use cli::{parse_part, Args, Selection};
+use utils::input;
+
+mod cli;
+
+const USAGE: &str = "\
+Usage: aoc run [<year>] <day|all> [--part <1|2>] [--input-dir <dir>]
+               [--submissions <file>]
+       aoc verify [[<year>] <day|all>] [--input-dir <dir>] [--answers <file>]
+                  [--record]
+       aoc submit [<year>] <day> --part <1|2> [--answer <answer>]
+                  [--input-dir <dir>] [--answers <file>] [--submissions <file>]
+       aoc history [<year>] <day> [--submissions <file>]
+       aoc new-day [<year>] <day>
+       aoc bench [<year>] <day|all> [--part <1|2>] [--input-dir <dir>]
+                 [--samples <n>] [--warmup <ms>] [--measure <ms>] [--save <file>]
+                 [--baseline <file>]
+
+Days are picked with an optional year and then a day number or \"all\", as in
+`aoc run 2023 5`. Without a year, the most recent year with a solution is
+used, and a year on its own, as in `aoc run 2023`, means every day of it.
+
+run     Runs one day's solution, or every day's, and prints the answers. Each
+        day's input is parsed once and shared by both parts; the time spent
+        parsing is shown separately from the time spent solving each part.
+        Answers that earlier submissions in --submissions (default
+        submissions.json at the root of the repository) show to be wrong are
+        flagged, such as one below a guess that was too low. The exit code is
+        non-zero if any solution panicked.
+
+verify  Runs solutions against every input they have: <year>/day<NN>.txt and
+        each <year>/day<NN>/<name>.txt in the input directory. Without any
+        days, every solution of every year is verified. Each part is reported as
+        pass, fail or unknown by comparing it with the answers stored for
+        that input in --answers (default answers.json at the root of the
+        repository). --record stores the answers of the unknown parts; only
+        use it once they have been accepted.
+
+submit  Submits the answer to one part, computed by running the solution
+        unless --answer is given, and prints whether it was right, too high,
+        too low or submitted too soon. Every judged answer is kept in
+        --submissions (default submissions.json at the root of the repository)
+        and is never submitted again, nor is anything once a part is solved.
+        A correct computed answer is also stored in --answers. Needs a session
+        token in aoc.toml.
+
+history Lists every answer submitted for a day with the server's reply, and
+        what they say about each part's answer.
+
+new-day Creates the crate for a day with a solution skeleton, adds it to the
+        workspace and the registry, and creates a placeholder example in
+        examples/<year>/day<NN>/. Refuses to touch a day that already exists.
+
+bench   Benchmarks parsing and each part: warms up for --warmup ms (default
+        300), then takes --samples samples (default 50) over about --measure ms
+        (default 1000). Prints the median, 95th percentile and throughput, and
+        how the median changed since the results in --baseline (default
+        bench.json at the root of the repository). The new results are merged
+        into --save (default the same file).
+
+Inputs are read from <input dir>/<year>/day<NN>.txt. If an input is missing
+and a session token is set in aoc.toml at the root of the repository (or the
+file named by AOC_CONFIG), it is downloaded there once along with a checksum;
+otherwise days whose input hasn't been downloaded are skipped when more than
+one day is selected.";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("run") => run_command(args),
+        Some("verify") => verify_command(args),
+        Some("submit") => submit_command(args),
+        Some("history") => history_command(args),
+        Some("new-day") => new_day_command(args),
+        Some("bench") => bench_command(args),
+        Some("help" | "--help" | "-h") => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Some(command) => Err(format!("unknown command {:?}", command)),
+        None => Err("no command given".to_string()),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run_command<I: Iterator<Item = String>>(args: I) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--part", "--input-dir", "--submissions"], &[])?;
+    let (selection, parts) = common_args(&args)?;
+
+    let Some(log) = submission_log(&args) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    let Some(provider) = input_provider(&args) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    let Some(inputs) = load_inputs(selection, &provider) else {
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let outcomes = inputs
+        .days
+        .iter()
+        .map(|(day, data)| run_day(day, &parts, data))
+        .collect::<Vec<_>>();
+
+    print_outcomes(&outcomes, &log);
+    inputs.report_missing(provider.dir());
+
+    if outcomes.iter().any(DayOutcome::panicked) {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+fn verify_command<I: Iterator<Item = String>>(args: I) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir", "--answers"], &["--record"])?;
+    let selection = match args.positional.as_slice() {
+        [] => Selection::Everything,
+        positional => Selection::parse(positional, registry::latest_year())?,
+    };
+    let Some(provider) = input_provider(&args) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    let answers_path = args
+        .option("--answers")
+        .map(PathBuf::from)
+        .unwrap_or_else(AnswerStore::default_path);
+    let mut store = match AnswerStore::load(&answers_path) {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", answers_path.display(), err);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    let days = selection.days();
+    if days.is_empty() {
+        return Err(no_solution(selection));
+    }
+
+    let mut table = Table::new(["Year", "Day", "Input", "Hash", "Part", "Answer", "Result"]);
+    let mut failed = false;
+    let mut recorded = 0;
+    for day in days {
+        // downloads the main input if it is missing, and checks it against its
+        // checksum if it was downloaded
+        if let Err(err) = provider.load(day.year, day.day) {
+            if !err.is_missing() {
+                eprintln!("error: {}", err);
+                failed = true;
+                continue;
+            }
+        }
+
+        let inputs = match input::find_inputs(provider.dir(), day.year, day.day) {
+            Ok(inputs) => inputs,
+            Err(err) => {
+                eprintln!(
+                    "error: failed to look for {} day {} inputs: {}",
+                    day.year, day.day, err
+                );
+                return Ok(ExitCode::FAILURE);
+            }
+        };
+
+        for named in inputs {
+            let data = match std::fs::read_to_string(&named.path) {
+                Ok(data) => data,
+                Err(err) => {
+                    eprintln!("error: failed to read {}: {}", named.path.display(), err);
+                    return Ok(ExitCode::FAILURE);
+                }
+            };
+            let hash = checksum(&data);
+            let outcome = run_day(day, &[1, 2], &data);
+            if outcome.parse_panicked {
+                failed = true;
+                table.push([
+                    day.year.to_string(),
+                    day.day.to_string(),
+                    named.name.clone(),
+                    hash[..12].to_string(),
+                    "-".to_string(),
+                    String::new(),
+                    "PANICKED while parsing".to_string(),
+                ]);
+                continue;
+            }
+
+            for part in outcome.parts {
+                let expected = store.get(day.year, day.day, part.part, &hash);
+                let verdict = Verdict::new(expected, part.answer.as_deref());
+                failed |= verdict.is_failure();
+                table.push([
+                    day.year.to_string(),
+                    day.day.to_string(),
+                    named.name.clone(),
+                    hash[..12].to_string(),
+                    part.part.to_string(),
+                    part.answer.clone().unwrap_or_default(),
+                    verdict.to_string(),
+                ]);
+
+                if let (Verdict::Unknown, true, Some(answer)) =
+                    (&verdict, args.switch("--record"), part.answer)
+                {
+                    store.insert(Answer {
+                        year: day.year,
+                        day: day.day,
+                        part: part.part,
+                        input: hash.clone(),
+                        answer,
+                    });
+                    recorded += 1;
+                }
+            }
+        }
+    }
+
+    if table.is_empty() {
+        println!("No inputs found in {}", provider.dir().display());
+    } else {
+        print!("{}", table);
+    }
+
+    if recorded > 0 {
+        if let Err(err) = store.save(&answers_path) {
+            eprintln!("error: failed to write {}: {}", answers_path.display(), err);
+            return Ok(ExitCode::FAILURE);
+        }
+        println!(
+            "\nRecorded {} answer(s) in {}",
+            recorded,
+            answers_path.display()
+        );
+    }
+
+    if failed {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+fn submit_command<I: Iterator<Item = String>>(args: I) -> Result<ExitCode, String> {
+    let args = Args::parse(
+        args,
+        &[
+            "--part",
+            "--answer",
+            "--input-dir",
+            "--answers",
+            "--submissions",
+        ],
+        &[],
+    )?;
+    let (year, day) = match Selection::parse(&args.positional, registry::latest_year())? {
+        Selection::Day(year, day) => (year, day),
+        _ => return Err("submit needs a single day".to_string()),
+    };
+    let part = parse_part(args.option("--part").ok_or("submit needs --part")?)?;
+    let answers_path = args
+        .option("--answers")
+        .map(PathBuf::from)
+        .unwrap_or_else(AnswerStore::default_path);
+    let submissions_path = args
+        .option("--submissions")
+        .map(PathBuf::from)
+        .unwrap_or_else(SubmissionLog::default_path);
+    let Some(mut log) = submission_log(&args) else {
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let config_path = Config::default_path();
+    let backend = match Config::load(&config_path) {
+        Ok(config) => HttpFetch::from_config(&config),
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", config_path.display(), err);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    let Some(backend) = backend else {
+        eprintln!(
+            "error: submitting needs the session token from adventofcode.com in {}",
+            config_path.display()
+        );
+        return Ok(ExitCode::FAILURE);
+    };
+    // the input is only needed when the answer has to be computed, and is
+    // kept to store a correct answer against its hash
+    let (answer, data) = match args.option("--answer") {
+        Some(answer) => (answer.trim().to_string(), None),
+        None => {
+            let Some(provider) = input_provider(&args) else {
+                return Ok(ExitCode::FAILURE);
+            };
+            let Some(mut inputs) = load_inputs(Selection::Day(year, day), &provider) else {
+                return Ok(ExitCode::FAILURE);
+            };
+            let (solution, data) = inputs.days.remove(0);
+            let outcome = run_day(solution, &[part], &data);
+            let Some(answer) = outcome.parts.first().and_then(|part| part.answer.clone()) else {
+                eprintln!("error: {} day {} part {} panicked", year, day, part);
+                return Ok(ExitCode::FAILURE);
+            };
+            (answer, Some(data))
+        }
+    };
+    if answer.is_empty() {
+        eprintln!("error: refusing to submit an empty answer");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    println!(
+        "Submitting {} for {} day {} part {}",
+        answer, year, day, part
+    );
+    let reply = match submit(&backend, &mut log, year, day, part, &answer) {
+        Ok(reply) => reply,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    println!("{}", reply);
+
+    if reply.is_judgement() {
+        if let Err(err) = log.save(&submissions_path) {
+            eprintln!(
+                "error: failed to write {}: {}",
+                submissions_path.display(),
+                err
+            );
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    if reply != Reply::Correct {
+        return Ok(ExitCode::FAILURE);
+    }
+    if let Some(data) = data {
+        let mut store = match AnswerStore::load(&answers_path) {
+            Ok(store) => store,
+            Err(err) => {
+                eprintln!("error: failed to read {}: {}", answers_path.display(), err);
+                return Ok(ExitCode::FAILURE);
+            }
+        };
+        store.insert(Answer {
+            year,
+            day,
+            part,
+            input: checksum(&data),
+            answer,
+        });
+        if let Err(err) = store.save(&answers_path) {
+            eprintln!("error: failed to write {}: {}", answers_path.display(), err);
+            return Ok(ExitCode::FAILURE);
+        }
+        println!("Stored the answer in {}", answers_path.display());
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn history_command<I: Iterator<Item = String>>(args: I) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--submissions"], &[])?;
+    let (year, day) = match Selection::parse(&args.positional, registry::latest_year())? {
+        Selection::Day(year, day) => (year, day),
+        _ => return Err("history needs a single day".to_string()),
+    };
+    let Some(log) = submission_log(&args) else {
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let mut table = Table::new(["Part", "Answer", "Reply", "Submitted (UTC)"]);
+    for part in [1, 2] {
+        for submission in log.history(year, day, part) {
+            table.push([
+                part.to_string(),
+                submission.answer.clone(),
+                submission.reply.to_string(),
+                format_timestamp(submission.at),
+            ]);
+        }
+    }
+    if table.is_empty() {
+        println!("Nothing has been submitted for {} day {}", year, day);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    print!("{}", table);
+    println!();
+    for part in [1, 2] {
+        let bounds = Bounds::from_history(log.history(year, day, part));
+        if !bounds.is_empty() {
+            println!("Part {}: {}", part, bounds);
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new_day_command<I: Iterator<Item = String>>(args: I) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &[], &[])?;
+    let (year, day) = match Selection::parse(&args.positional, registry::latest_year())? {
+        Selection::Day(year, day) => (year, day),
+        _ => return Err("new-day needs a single day".to_string()),
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner to live inside the workspace");
+    match scaffold::new_day(root, year, day) {
+        Ok(changed) => {
+            for path in changed {
+                println!(
+                    "wrote {}",
+                    path.strip_prefix(root).unwrap_or(&path).display()
+                );
+            }
+            println!(
+                "\n{} day {} is ready: fill in {}/day{:02}/src/lib.rs and the example, then run it with `aoc run {} {}`.",
+                year, day, year, day, year, day
+            );
+            Ok(ExitCode::SUCCESS)
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+fn bench_command<I: Iterator<Item = String>>(args: I) -> Result<ExitCode, String> {
+    let args = Args::parse(
+        args,
+        &[
+            "--part",
+            "--input-dir",
+            "--samples",
+            "--warmup",
+            "--measure",
+            "--save",
+            "--baseline",
+        ],
+        &[],
+    )?;
+    let (selection, parts) = common_args(&args)?;
+
+    let defaults = BenchConfig::default();
+    let millis = |flag, default: Duration| match args.option(flag) {
+        Some(value) => value
+            .parse()
+            .map(Duration::from_millis)
+            .map_err(|_| format!("{} must be a whole number of milliseconds", flag)),
+        None => Ok(default),
+    };
+    let config = BenchConfig {
+        warmup: millis("--warmup", defaults.warmup)?,
+        measurement: millis("--measure", defaults.measurement)?,
+        samples: match args.option("--samples") {
+            Some(value) => value
+                .parse()
+                .ok()
+                .filter(|samples| *samples > 0)
+                .ok_or("--samples must be a positive number")?,
+            None => defaults.samples,
+        },
+    };
+    let save_path = args
+        .option("--save")
+        .map(PathBuf::from)
+        .unwrap_or_else(BenchResults::default_path);
+    let baseline_path = args
+        .option("--baseline")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| save_path.clone());
+
+    let Some(provider) = input_provider(&args) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    let Some(inputs) = load_inputs(selection, &provider) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    let (baseline, mut saved) = match (
+        BenchResults::load(&baseline_path),
+        BenchResults::load(&save_path),
+    ) {
+        (Ok(baseline), Ok(saved)) => (baseline, saved),
+        (Err(err), _) => {
+            eprintln!("error: failed to read {}: {}", baseline_path.display(), err);
+            return Ok(ExitCode::FAILURE);
+        }
+        (_, Err(err)) => {
+            eprintln!("error: failed to read {}: {}", save_path.display(), err);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    let mut table = Table::new([
+        "Year",
+        "Day",
+        "Stage",
+        "Median",
+        "p95",
+        "Throughput",
+        "Change",
+    ]);
+    let mut panicked = false;
+    for (day, data) in inputs.days.iter() {
+        // a plain run first, so that a solution that panics is reported once
+        // instead of being benchmarked
+        let outcome = run_day(day, &parts, data);
+        panicked |= outcome.panicked();
+        if outcome.parse_panicked {
+            continue;
+        }
+
+        let mut records = vec![BenchRecord {
+            year: day.year,
+            day: day.day,
+            stage: Stage::Parse,
+            input_bytes: data.len(),
+            stats: bench(&config, || (day.parse)(data)),
+        }];
+
+        let input = (day.parse)(data);
+        for part in outcome.parts.iter().filter(|part| !part.panicked()) {
+            let solve = day.part(part.part);
+            records.push(BenchRecord {
+                year: day.year,
+                day: day.day,
+                stage: Stage::for_part(part.part),
+                input_bytes: data.len(),
+                stats: bench(&config, || solve(input.as_ref())),
+            });
+        }
+
+        for record in records {
+            let change = match baseline.find(record.year, record.day, record.stage) {
+                None => "new".to_string(),
+                Some(old) => Change::between(&old.stats, &record.stats)
+                    .map_or_else(|| "-".to_string(), |change| change.to_string()),
+            };
+            table.push([
+                record.year.to_string(),
+                record.day.to_string(),
+                record.stage.to_string(),
+                format_nanos(record.stats.median_ns),
+                format_nanos(record.stats.p95_ns),
+                record
+                    .stats
+                    .throughput(record.input_bytes)
+                    .map_or_else(|| "-".to_string(), format_throughput),
+                change,
+            ]);
+            saved.insert(record);
+        }
+    }
+
+    if !table.is_empty() {
+        print!("{}", table);
+        if let Err(err) = saved.save(&save_path) {
+            eprintln!("error: failed to write {}: {}", save_path.display(), err);
+            return Ok(ExitCode::FAILURE);
+        }
+        println!("\nResults saved to {}", save_path.display());
+    }
+    inputs.report_missing(provider.dir());
+
+    if panicked {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+/// The day and `--part` arguments shared by every command that runs
+/// solutions.
+fn common_args(args: &Args) -> Result<(Selection, Vec<u8>), String> {
+    let selection = Selection::parse(&args.positional, registry::latest_year())?;
+    let parts = match args.option("--part") {
+        Some(part) => vec![parse_part(part)?],
+        None => vec![1, 2],
+    };
+
+    Ok((selection, parts))
+}
+
+/// Reads `--submissions`, or the default submission log. Returns `None` after
+/// printing the error if it can't be read.
+fn submission_log(args: &Args) -> Option<SubmissionLog> {
+    let path = args
+        .option("--submissions")
+        .map(PathBuf::from)
+        .unwrap_or_else(SubmissionLog::default_path);
+    match SubmissionLog::load(&path) {
+        Ok(log) => Some(log),
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", path.display(), err);
+            None
+        }
+    }
+}
+
+/// Reads inputs from `--input-dir`, or the default input directory, and
+/// downloads missing ones if the config has a session token. Returns `None`
+/// after printing the error if the config can't be read.
+fn input_provider(args: &Args) -> Option<InputProvider> {
+    let input_dir = match args.option("--input-dir") {
+        Some(dir) => dir.into(),
+        None => input::default_input_dir(),
+    };
+    let config_path = Config::default_path();
+    let config = match Config::load(&config_path) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", config_path.display(), err);
+            return None;
+        }
+    };
+    let backend = HttpFetch::from_config(&config).map(|fetch| Box::new(fetch) as Box<dyn Fetch>);
+
+    Some(InputProvider::new(input_dir, backend))
+}
+
+/// The selected days along with their inputs.
+struct Inputs {
+    days: Vec<(&'static Day, String)>,
+    /// Days skipped because their input hasn't been downloaded and can't be, as
+    /// `(year, day)`.
+    missing: Vec<(u16, u8)>,
+}
+
+impl Inputs {
+    fn report_missing(&self, input_dir: &Path) {
+        if self.missing.is_empty() {
+            return;
+        }
+
+        let days = self
+            .missing
+            .iter()
+            .map(|(year, day)| format!("{} day {}", year, day))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "\nSkipped days with no input in {}: {}",
+            input_dir.display(),
+            days
+        );
+    }
+}
+
+/// Loads the input of every selected day. A missing input is only an error
+/// when a single day was asked for; otherwise the day is skipped. Returns
+/// `None` after printing the error if an input can't be loaded.
+fn load_inputs(selection: Selection, provider: &InputProvider) -> Option<Inputs> {
+    let days = selection.days();
+    if days.is_empty() {
+        eprintln!("error: {}", no_solution(selection));
+        return None;
+    }
+
+    let mut inputs = Inputs {
+        days: Vec::new(),
+        missing: Vec::new(),
+    };
+    for day in days {
+        match provider.load(day.year, day.day) {
+            Ok(data) => inputs.days.push((day, data)),
+            Err(err) if err.is_missing() && !matches!(selection, Selection::Day(..)) => {
+                inputs.missing.push((day.year, day.day))
+            }
+            Err(err) => {
+                eprintln!("error: {}", err);
+                return None;
+            }
+        }
+    }
+
+    Some(inputs)
+}
+
+fn no_solution(selection: Selection) -> String {
+    match selection {
+        Selection::Everything => "there are no solutions yet".to_string(),
+        Selection::Year(year) => format!("{} has no solutions", year),
+        Selection::Day(year, day) => format!("{} day {} has no solution", year, day),
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format_duration(Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Prints one row per part. The parse time is shown on the first row of each
+/// day, since both parts share the parsed input. Answers that `log` shows to
+/// be wrong are flagged.
+fn print_outcomes(outcomes: &[DayOutcome], log: &SubmissionLog) {
+    let mut table = Table::new(["Year", "Day", "Part", "Answer", "Check", "Parse", "Solve"]);
+    for outcome in outcomes {
+        let parse_elapsed = format_duration(outcome.parse_elapsed);
+        if outcome.parse_panicked {
+            table.push([
+                outcome.year.to_string(),
+                outcome.day.to_string(),
+                "-".to_string(),
+                "parse panicked".to_string(),
+                String::new(),
+                parse_elapsed,
+                String::new(),
+            ]);
+            continue;
+        }
+
+        for (i, part) in outcome.parts.iter().enumerate() {
+            let bounds = Bounds::from_history(log.history(outcome.year, outcome.day, part.part));
+            let check = match &part.answer {
+                Some(answer) if bounds.correct.as_ref() == Some(answer) => "correct".to_string(),
+                Some(answer) => bounds
+                    .check(answer)
+                    .map(|flag| flag.to_string())
+                    .unwrap_or_default(),
+                None => String::new(),
+            };
+            table.push([
+                outcome.year.to_string(),
+                outcome.day.to_string(),
+                part.part.to_string(),
+                part.answer
+                    .clone()
+                    .unwrap_or_else(|| "panicked".to_string()),
+                check,
+                if i == 0 {
+                    parse_elapsed.clone()
+                } else {
+                    String::new()
+                },
+                format_duration(part.elapsed),
+            ]);
+        }
+    }
+
+    if !table.is_empty() {
+        print!("{}", table);
+    }
+}
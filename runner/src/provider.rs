@@ -0,0 +1,266 @@
+//! Where the runner gets puzzle inputs from. The input directory doubles as a
+//! cache: an input found there is always used as is, and only a missing one
+//! is downloaded, once, through the fetch backend. A downloaded input is saved
+//! next to a `day<NN>.txt.sha256` checksum in the format `sha256sum` writes,
+//! and is checked against it every time it is read, so an input that was
+//! edited or truncated by accident is caught instead of silently giving wrong
+//! answers.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+use utils::input::{self, InputError};
+
+use crate::fetch::{Fetch, FetchError};
+
+#[derive(Debug)]
+pub enum ProviderError {
+    /// The input isn't cached and couldn't be downloaded, or couldn't be read.
+    Input(InputError),
+    /// The cached input no longer matches the checksum saved when it was
+    /// downloaded.
+    Checksum {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+    Fetch {
+        year: u16,
+        day: u8,
+        source: FetchError,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl ProviderError {
+    /// Whether the input simply hasn't been downloaded and there is no backend
+    /// to download it with.
+    pub fn is_missing(&self) -> bool {
+        matches!(self, Self::Input(InputError::Missing { .. }))
+    }
+}
+
+impl Display for ProviderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Input(err) => write!(f, "{}", err),
+            Self::Checksum {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} has changed since it was downloaded (checksum {}, expected {}). Restore it, or delete it and its .sha256 file to download it again.",
+                path.display(),
+                actual,
+                expected
+            ),
+            Self::Fetch { year, day, source } => {
+                write!(f, "failed to download the {} day {} input: {}", year, day, source)
+            }
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for ProviderError {}
+
+pub struct InputProvider {
+    dir: PathBuf,
+    backend: Option<Box<dyn Fetch>>,
+}
+
+impl InputProvider {
+    /// Reads inputs from `dir`, downloading missing ones with `backend` if
+    /// there is one.
+    pub fn new(dir: PathBuf, backend: Option<Box<dyn Fetch>>) -> Self {
+        Self { dir, backend }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, ProviderError> {
+        let path = input::input_path(&self.dir, year, day);
+        match input::load_from(&self.dir, year, day) {
+            Ok(data) => {
+                verify_checksum(&path, &data)?;
+                Ok(data)
+            }
+            Err(InputError::Missing { .. }) if self.backend.is_some() => {
+                self.download(&path, year, day)
+            }
+            Err(err) => Err(ProviderError::Input(err)),
+        }
+    }
+
+    fn download(&self, path: &Path, year: u16, day: u8) -> Result<String, ProviderError> {
+        let backend = self.backend.as_ref().expect("a backend to download with");
+        let data = backend
+            .fetch_input(year, day)
+            .map_err(|source| ProviderError::Fetch { year, day, source })?;
+
+        let io_error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| ProviderError::Io { path, source }
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error(dir))?;
+        }
+
+        // the input is renamed into place in one step, so an interrupted
+        // download leaves no input behind and is simply tried again next time
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, &data).map_err(io_error(&partial))?;
+        fs::rename(&partial, path).map_err(io_error(path))?;
+
+        // written only once the input is in place, so a checksum never sits
+        // next to a missing input
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .expect("input path to have a file name");
+        let checksum_path = checksum_path(path);
+        fs::write(
+            &checksum_path,
+            format!("{}  {}\n", checksum(&data), file_name),
+        )
+        .map_err(io_error(&checksum_path))?;
+
+        Ok(data)
+    }
+}
+
+/// The SHA-256 of `data`, as lowercase hex.
+pub fn checksum(data: &str) -> String {
+    Sha256::digest(data.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+pub fn checksum_path(input_path: &Path) -> PathBuf {
+    input_path.with_extension("txt.sha256")
+}
+
+/// Checks `data` against the checksum saved next to `path`. Inputs that were
+/// put there by hand have no checksum and are accepted.
+fn verify_checksum(path: &Path, data: &str) -> Result<(), ProviderError> {
+    let checksum_path = checksum_path(path);
+    let saved = match fs::read_to_string(&checksum_path) {
+        Ok(saved) => saved,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(source) => {
+            return Err(ProviderError::Io {
+                path: checksum_path,
+                source,
+            })
+        }
+    };
+
+    let expected = saved.split_whitespace().next().unwrap_or_default();
+    let actual = checksum(data);
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(ProviderError::Checksum {
+            path: path.to_path_buf(),
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checksum, InputProvider, ProviderError};
+    use crate::{fetch::HttpFetch, stub::StubServer};
+    use std::{fs, path::PathBuf};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("aoc-provider-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn checksum_is_sha256() {
+        assert_eq!(
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            checksum("")
+        );
+    }
+
+    #[test]
+    fn downloads_once_and_caches() {
+        let server = StubServer::start(|request| (200, format!("input for {}\n", request.path)));
+        let dir = temp_dir("cache");
+        let provider = InputProvider::new(
+            dir.clone(),
+            Some(Box::new(HttpFetch::new(&server.url(), "secret"))),
+        );
+
+        let data = provider.load(2023, 5).unwrap();
+        assert_eq!("input for /2023/day/5/input\n", data);
+        assert_eq!(data, provider.load(2023, 5).unwrap());
+        assert_eq!(1, server.requests().len());
+
+        assert_eq!(
+            data,
+            fs::read_to_string(dir.join("2023/day05.txt")).unwrap()
+        );
+        assert_eq!(
+            format!("{}  day05.txt\n", checksum(&data)),
+            fs::read_to_string(dir.join("2023/day05.txt.sha256")).unwrap()
+        );
+        assert!(!dir.join("2023/day05.txt.part").exists());
+
+        fs::write(dir.join("2023/day05.txt"), "edited\n").unwrap();
+        assert!(matches!(
+            provider.load(2023, 5),
+            Err(ProviderError::Checksum { .. })
+        ));
+        assert_eq!(1, server.requests().len());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_download_leaves_nothing_behind() {
+        let server = StubServer::start(|_| (400, "Please log in".to_string()));
+        let dir = temp_dir("failed");
+        let provider = InputProvider::new(
+            dir.clone(),
+            Some(Box::new(HttpFetch::new(&server.url(), "expired"))),
+        );
+
+        let err = provider.load(2023, 5).unwrap_err();
+        assert!(matches!(err, ProviderError::Fetch { .. }));
+        assert!(!dir.join("2023/day05.txt").exists());
+        assert!(!dir.join("2023/day05.txt.sha256").exists());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn hand_placed_inputs_need_no_backend() {
+        let dir = temp_dir("manual");
+        fs::create_dir_all(dir.join("2023")).unwrap();
+        fs::write(dir.join("2023/day01.txt"), "1abc2\n").unwrap();
+        let provider = InputProvider::new(dir.clone(), None);
+
+        assert_eq!("1abc2\n", provider.load(2023, 1).unwrap());
+        assert!(provider.load(2023, 2).unwrap_err().is_missing());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
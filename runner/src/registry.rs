@@ -0,0 +1,79 @@
+use std::any::Any;
+
+use utils::Solution;
+
+/// A day's solution as the runner sees it, with the typed input hidden behind
+/// `Any` so every day fits in one table. `parse` is called once per run and
+/// both parts are given the result.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub parse: fn(&str) -> Box<dyn Any>,
+    pub part1: fn(&dyn Any) -> String,
+    pub part2: fn(&dyn Any) -> String,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u16, day: u8) -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            year,
+            day,
+            parse: |data| Box::new(S::parse(data)),
+            part1: |input| S::part1(downcast::<S>(input)).to_string(),
+            part2: |input| S::part2(downcast::<S>(input)).to_string(),
+        }
+    }
+
+    pub fn part(&self, part: u8) -> fn(&dyn Any) -> String {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => panic!("part must be 1 or 2, not {}", part),
+        }
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("input to have been parsed by the same day")
+}
+
+/// Every day that has a solution, in order of year and then day. `aoc new-day`
+/// adds to this.
+pub static DAYS: &[Day] = &[
+    Day::new::<aoc2023_day01::Day01>(2023, 1),
+    Day::new::<aoc2023_day02::Day02>(2023, 2),
+    Day::new::<aoc2023_day03::Day03>(2023, 3),
+    Day::new::<aoc2023_day04::Day04>(2023, 4),
+    Day::new::<aoc2023_day05::Day05>(2023, 5),
+    Day::new::<aoc2023_day06::Day06>(2023, 6),
+    Day::new::<aoc2023_day07::Day07>(2023, 7),
+    Day::new::<aoc2023_day08::Day08>(2023, 8),
+    Day::new::<aoc2023_day09::Day09>(2023, 9),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|entry| (entry.year, entry.day) == (year, day))
+}
+
+/// Every day of `year` that has a solution.
+pub fn days_of(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |entry| entry.year == year)
+}
+
+/// The most recent year with a solution, which commands use when no year is
+/// given.
+pub fn latest_year() -> u16 {
+    DAYS.iter()
+        .map(|entry| entry.year)
+        .max()
+        .expect("at least one day to be registered")
+}
@@ -0,0 +1,78 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::registry::Day;
+
+/// The result of running one day: how long parsing took and the outcome of
+/// each part that was asked for.
+pub struct DayOutcome {
+    pub year: u16,
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    /// Empty if parsing panicked.
+    pub parts: Vec<Outcome>,
+    pub parse_panicked: bool,
+}
+
+impl DayOutcome {
+    pub fn panicked(&self) -> bool {
+        self.parse_panicked || self.parts.iter().any(Outcome::panicked)
+    }
+}
+
+/// The result of solving one part of one day.
+pub struct Outcome {
+    pub part: u8,
+    /// The answer, or `None` if the solution panicked.
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl Outcome {
+    pub fn panicked(&self) -> bool {
+        self.answer.is_none()
+    }
+}
+
+/// Parses `data` once and solves each of `parts` from the parsed input,
+/// timing the parse separately from each part.
+///
+/// Panics are caught so that one broken solution doesn't stop the others from
+/// running. The panic message itself is printed to stderr by the default
+/// panic hook.
+pub fn run_day(day: &Day, parts: &[u8], data: &str) -> DayOutcome {
+    let start = Instant::now();
+    let input = panic::catch_unwind(AssertUnwindSafe(|| (day.parse)(data)));
+    let parse_elapsed = start.elapsed();
+
+    let parts = match &input {
+        Ok(input) => parts
+            .iter()
+            .map(|part| solve_part(day, *part, input.as_ref()))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    DayOutcome {
+        year: day.year,
+        day: day.day,
+        parse_elapsed,
+        parts,
+        parse_panicked: input.is_err(),
+    }
+}
+
+fn solve_part(day: &Day, part: u8, input: &dyn Any) -> Outcome {
+    let solve = day.part(part);
+    let start = Instant::now();
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| solve(input))).ok();
+
+    Outcome {
+        part,
+        answer,
+        elapsed: start.elapsed(),
+    }
+}
@@ -0,0 +1,322 @@
+//! Generates the crate for a new day and wires it into the workspace: the
+//! workspace members, the runner's dependencies and the registry, plus a
+//! placeholder example. Nothing is written unless every step can be done, and
+//! existing files are never overwritten.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day already has a crate, an example directory or a registry entry.
+    Exists(PathBuf),
+    /// A file that has to be edited doesn't look the way it is expected to.
+    UnexpectedLayout {
+        path: PathBuf,
+        reason: String,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exists(path) => write!(f, "{} already exists", path.display()),
+            Self::UnexpectedLayout { path, reason } => {
+                write!(f, "can't update {}: {}", path.display(), reason)
+            }
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Creates day `day` of `year` in the workspace at `root`, as the crate
+/// `<year>/day<NN>`. Returns the files that were created or changed.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let dir_name = format!("day{:02}", day);
+    let package = format!("aoc{}-day{:02}", year, day);
+    let crate_dir = root.join(year.to_string()).join(&dir_name);
+    let examples_dir = root.join("examples").join(year.to_string()).join(&dir_name);
+    for path in [&crate_dir, &examples_dir] {
+        if path.exists() {
+            return Err(ScaffoldError::Exists(path.clone()));
+        }
+    }
+
+    // work out every edit before writing anything, so a failure part way
+    // through can't leave the workspace half wired up
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("runner").join("Cargo.toml");
+    let registry = root.join("runner").join("src").join("registry.rs");
+    let edits: [Edit; 3] = [
+        (
+            &workspace_manifest,
+            |line: &str| line.trim_start().starts_with("\"20"),
+            format!("  \"{}/{}\",", year, dir_name),
+        ),
+        (
+            &runner_manifest,
+            |line: &str| line.starts_with("aoc20"),
+            format!("{} = {{ path = \"../{}/{}\" }}", package, year, dir_name),
+        ),
+        (
+            &registry,
+            |line: &str| line.trim_start().starts_with("Day::new::<aoc20"),
+            format!(
+                "    Day::new::<{}::Day{:02}>({}, {}),",
+                package.replace('-', "_"),
+                day,
+                year,
+                day
+            ),
+        ),
+    ];
+    let edits = edits
+        .into_iter()
+        .map(|(path, is_entry, line)| {
+            let text = read(path)?;
+            insert_sorted(&text, is_entry, &line)
+                .map(|text| (path.clone(), text))
+                .map_err(|reason| match reason {
+                    Inserted::Duplicate => ScaffoldError::Exists(path.clone()),
+                    Inserted::NoEntries => ScaffoldError::UnexpectedLayout {
+                        path: path.clone(),
+                        reason: "no existing day entries to add to".to_string(),
+                    },
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let files = [
+        (crate_dir.join("Cargo.toml"), cargo_toml(&package)),
+        (crate_dir.join("src").join("lib.rs"), lib_rs(year, day)),
+        (examples_dir.join("1.txt"), String::new()),
+        (examples_dir.join("1.expected"), EXPECTED.to_string()),
+    ];
+
+    let mut changed = Vec::new();
+    for (path, contents) in files.into_iter().chain(edits) {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| ScaffoldError::Io {
+                path: dir.to_path_buf(),
+                source,
+            })?;
+        }
+        fs::write(&path, contents).map_err(|source| ScaffoldError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        changed.push(path);
+    }
+
+    Ok(changed)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// A file to edit, which of its lines are day entries, and the entry to add.
+type Edit<'p> = (&'p PathBuf, fn(&str) -> bool, String);
+
+enum Inserted {
+    Duplicate,
+    NoEntries,
+}
+
+/// Adds `new_line` to the run of lines for which `is_entry` is true, keeping
+/// them in sorted order.
+fn insert_sorted(
+    text: &str,
+    is_entry: fn(&str) -> bool,
+    new_line: &str,
+) -> Result<String, Inserted> {
+    let lines = text.lines().collect::<Vec<_>>();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| is_entry(line))
+        .collect::<Vec<_>>();
+
+    if entries
+        .iter()
+        .any(|(_, line)| line.trim() == new_line.trim())
+    {
+        return Err(Inserted::Duplicate);
+    }
+
+    let index = match entries
+        .iter()
+        .find(|(_, line)| line.trim() > new_line.trim())
+    {
+        Some((index, _)) => *index,
+        None => entries.last().ok_or(Inserted::NoEntries)?.0 + 1,
+    };
+
+    let mut result = lines[..index].join("\n");
+    if index > 0 {
+        result.push('\n');
+    }
+    result.push_str(new_line);
+    for line in lines[index..].iter() {
+        result.push('\n');
+        result.push_str(line);
+    }
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+
+    Ok(result)
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+tokenizer = {{ path = "../../parser" }}
+utils = {{ path = "../../utils" }}
+"#,
+        name
+    )
+}
+
+fn lib_rs(year: u16, day: u8) -> String {
+    LIB_RS
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{padded_day}", &format!("{:02}", day))
+}
+
+const LIB_RS: &str = r#"use std::fmt::Display;
+use tokenizer::{FromPuzzleInput, ParseError};
+use utils::Solution;
+
+pub struct Day{padded_day};
+
+impl Solution for Day{padded_day} {
+    type Input = Vec<Line>;
+
+    fn parse(data: &str) -> Self::Input {
+        parse_input(data).unwrap_or_else(|err| panic!("Failed to parse input. Message: {}", err))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input.len()
+    }
+
+    fn part2(_input: &Self::Input) -> impl Display {
+        ""
+    }
+}
+
+fn parse_input(data: &str) -> Result<Vec<Line>, ParseError> {
+    tokenizer::parse_lines(data)
+}
+
+#[derive(Debug, FromPuzzleInput)]
+#[puzzle("{word}")]
+pub struct Line {
+    pub word: String,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn parses_actual_input() {
+        let data = utils::require_input!({year}, {day});
+        if let Err(err) = super::parse_input(&data) {
+            panic!("{}", err);
+        }
+    }
+}
+"#;
+
+const EXPECTED: &str = "\
+# Paste the example from the puzzle into 1.txt and its answers below.
+# part1:
+# part2:
+";
+
+#[cfg(test)]
+mod tests {
+    use super::{insert_sorted, new_day, ScaffoldError};
+    use std::fs;
+
+    #[test]
+    fn inserts_in_order() {
+        let text =
+            "[workspace]\nmembers = [\n  \"2023/day01\",\n  \"2023/day03\",\n  \"runner\",\n]\n";
+        let is_entry = |line: &str| line.trim_start().starts_with("\"20");
+        assert_eq!(
+            Ok("[workspace]\nmembers = [\n  \"2023/day01\",\n  \"2023/day02\",\n  \"2023/day03\",\n  \"runner\",\n]\n".to_string()),
+            insert_sorted(text, is_entry, "  \"2023/day02\",").map_err(|_| ())
+        );
+        assert_eq!(
+            Ok("[workspace]\nmembers = [\n  \"2023/day01\",\n  \"2023/day03\",\n  \"2024/day01\",\n  \"runner\",\n]\n".to_string()),
+            insert_sorted(text, is_entry, "  \"2024/day01\",").map_err(|_| ())
+        );
+        assert!(insert_sorted(text, is_entry, "  \"2023/day03\",").is_err());
+    }
+
+    #[test]
+    fn generates_and_refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("runner/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n  \"2023/day01\",\n  \"runner\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("runner/Cargo.toml"),
+            "[dependencies]\naoc2023-day01 = { path = \"../2023/day01\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("runner/src/registry.rs"),
+            "pub static DAYS: &[Day] = &[\n    Day::new::<aoc2023_day01::Day01>(2023, 1),\n];\n",
+        )
+        .unwrap();
+
+        let changed = new_day(&root, 2023, 2).unwrap();
+        assert_eq!(7, changed.len());
+        assert!(fs::read_to_string(root.join("2023/day02/src/lib.rs"))
+            .unwrap()
+            .contains("impl Solution for Day02"));
+        assert!(fs::read_to_string(root.join("2023/day02/Cargo.toml"))
+            .unwrap()
+            .contains("name = \"aoc2023-day02\""));
+        assert!(fs::read_to_string(root.join("runner/src/registry.rs"))
+            .unwrap()
+            .contains("    Day::new::<aoc2023_day01::Day01>(2023, 1),\n    Day::new::<aoc2023_day02::Day02>(2023, 2),\n];"));
+        assert!(root.join("examples/2023/day02/1.expected").exists());
+
+        let lib = root.join("2023/day02/src/lib.rs");
+        fs::write(&lib, "// my work").unwrap();
+        assert!(matches!(
+            new_day(&root, 2023, 2),
+            Err(ScaffoldError::Exists(_))
+        ));
+        assert_eq!("// my work", fs::read_to_string(&lib).unwrap());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,399 @@
+//! Submitting answers. Every answer the server judged is recorded in a
+//! submission log, `submissions.json` at the root of the repository by
+//! default, and an answer that is already in the log is never sent again: a
+//! wrong answer stays wrong, and a part that has been solved needs no more
+//! guesses. The log belongs to one account, so it is ignored by git.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bounds::{Bounds, Flag},
+    fetch::{FetchError, HttpFetch},
+};
+
+pub trait Submit {
+    /// Posts `answer` and returns the page the server answered with.
+    fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, FetchError>;
+}
+
+impl Submit for HttpFetch {
+    fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, FetchError> {
+        self.post_form(
+            self.url(&format!("/{}/day/{}/answer", year, day)),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+    }
+}
+
+/// What the server made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reply {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Incorrect,
+    /// An answer was submitted too recently; try again after this many
+    /// seconds. The answer wasn't judged.
+    Wait(u64),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A page that isn't recognised, as text.
+    Unrecognised(String),
+}
+
+impl Reply {
+    /// Reads the reply out of the page the server answered with.
+    pub fn parse(page: &str) -> Self {
+        let text = article_text(page);
+        if text.contains("That's the right answer") {
+            Self::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Self::TooHigh
+            } else if text.contains("too low") {
+                Self::TooLow
+            } else {
+                Self::Incorrect
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Self::Wait(wait_seconds(&text).unwrap_or(60))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unrecognised(text)
+        }
+    }
+
+    /// Whether the server judged the answer, so that it is worth remembering.
+    pub fn is_judgement(&self) -> bool {
+        matches!(
+            self,
+            Self::Correct | Self::TooHigh | Self::TooLow | Self::Incorrect
+        )
+    }
+}
+
+impl Display for Reply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "wrong, too high"),
+            Self::TooLow => write!(f, "wrong, too low"),
+            Self::Incorrect => write!(f, "wrong"),
+            Self::Wait(seconds) => write!(f, "submitted too recently, wait {}s", seconds),
+            Self::WrongLevel => write!(f, "that part is already solved or still locked"),
+            Self::Unrecognised(text) => write!(f, "unrecognised reply: {}", text),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, or of the whole page if it has none,
+/// with the tags taken out.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "You have 1m 5s left to wait" as 65 seconds.
+fn wait_seconds(text: &str) -> Option<u64> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let number = number.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub reply: Reply,
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub at: u64,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionLog {
+    pub submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("runner to live inside the workspace")
+            .join("submissions.json")
+    }
+
+    /// Reads a log, or returns an empty one if the file doesn't exist.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    /// Every judged submission for one part, oldest first.
+    pub fn history(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |submission| {
+            (submission.year, submission.day, submission.part) == (year, day, part)
+        })
+    }
+
+    /// The earlier submission that rules out sending `answer`: the same
+    /// answer, or any correct one.
+    pub fn blocking(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<&Submission> {
+        self.history(year, day, part)
+            .find(|submission| submission.answer == answer || submission.reply == Reply::Correct)
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The log already has a judgement that makes submitting pointless.
+    AlreadySubmitted(Submission),
+    /// Earlier guesses that were too high or too low rule the answer out.
+    OutOfBounds(Flag),
+    Fetch(FetchError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadySubmitted(submission) if submission.reply == Reply::Correct => write!(
+                f,
+                "part {} is already solved with {}",
+                submission.part, submission.answer
+            ),
+            Self::AlreadySubmitted(submission) => write!(
+                f,
+                "{} was already submitted for part {} and was {}",
+                submission.answer, submission.part, submission.reply
+            ),
+            Self::OutOfBounds(flag) => write!(f, "not submitting an answer that is {}", flag),
+            Self::Fetch(err) => write!(f, "failed to submit: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// Submits `answer` unless the log rules it out, either because it was judged
+/// before or because it is outside the bounds set by earlier guesses, and
+/// records the reply in the log if the answer was judged. The caller saves
+/// the log.
+pub fn submit(
+    backend: &dyn Submit,
+    log: &mut SubmissionLog,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Reply, SubmitError> {
+    if let Some(submission) = log.blocking(year, day, part, answer) {
+        return Err(SubmitError::AlreadySubmitted(submission.clone()));
+    }
+    if let Some(flag) = Bounds::from_history(log.history(year, day, part)).check(answer) {
+        return Err(SubmitError::OutOfBounds(flag));
+    }
+
+    let page = backend
+        .submit_answer(year, day, part, answer)
+        .map_err(SubmitError::Fetch)?;
+    let reply = Reply::parse(&page);
+    if reply.is_judgement() {
+        log.submissions.push(Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            reply: reply.clone(),
+            at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+        });
+    }
+
+    Ok(reply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{submit, Reply, SubmissionLog, SubmitError};
+    use crate::{bounds::Flag, fetch::HttpFetch, stub::StubServer};
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            text
+        )
+    }
+
+    #[test]
+    fn replies() {
+        assert_eq!(
+            Reply::Correct,
+            Reply::parse(&page("That's the right answer! You are <span class=\"day-success\">one gold star</span> closer."))
+        );
+        assert_eq!(
+            Reply::TooHigh,
+            Reply::parse(&page(
+                "That's not the right answer; your answer is too high. If you're stuck, ..."
+            ))
+        );
+        assert_eq!(
+            Reply::TooLow,
+            Reply::parse(&page(
+                "That's not the right answer; your answer is too low."
+            ))
+        );
+        assert_eq!(
+            Reply::Incorrect,
+            Reply::parse(&page("That's not the right answer. If you're stuck, ..."))
+        );
+        assert_eq!(
+            Reply::Wait(83),
+            Reply::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait."))
+        );
+        assert_eq!(
+            Reply::Wait(9),
+            Reply::parse(&page(
+                "You gave an answer too recently. You have 9s left to wait."
+            ))
+        );
+        assert_eq!(
+            Reply::WrongLevel,
+            Reply::parse(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ))
+        );
+        assert_eq!(
+            Reply::Unrecognised("Something else".to_string()),
+            Reply::parse(&page("Something <em>else</em>"))
+        );
+    }
+
+    #[test]
+    fn never_resubmits_a_judged_answer() {
+        let server = StubServer::start(|request| {
+            if request.body.contains("answer=42") {
+                (200, page("That's the right answer!"))
+            } else {
+                (
+                    200,
+                    page("That's not the right answer; your answer is too low."),
+                )
+            }
+        });
+        let backend = HttpFetch::new(&server.url(), "secret");
+        let mut log = SubmissionLog::default();
+
+        assert_eq!(
+            Reply::TooLow,
+            submit(&backend, &mut log, 2023, 5, 1, "12").unwrap()
+        );
+        assert!(matches!(
+            submit(&backend, &mut log, 2023, 5, 1, "12"),
+            Err(SubmitError::AlreadySubmitted(_))
+        ));
+        assert!(matches!(
+            submit(&backend, &mut log, 2023, 5, 1, "7"),
+            Err(SubmitError::OutOfBounds(Flag::TooLow(12)))
+        ));
+        assert_eq!(1, server.requests().len());
+
+        assert_eq!(
+            Reply::Correct,
+            submit(&backend, &mut log, 2023, 5, 1, "42").unwrap()
+        );
+        let err = submit(&backend, &mut log, 2023, 5, 1, "43").unwrap_err();
+        assert_eq!("part 1 is already solved with 42", err.to_string());
+        assert_eq!(2, log.history(2023, 5, 1).count());
+        assert_eq!(0, log.history(2023, 5, 2).count());
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert_eq!("POST", requests[0].method);
+        assert_eq!("/2023/day/5/answer", requests[0].path);
+        assert_eq!("level=1&answer=12", requests[0].body);
+        assert_eq!(Some("session=secret"), requests[0].header("cookie"));
+    }
+
+    #[test]
+    fn waiting_is_not_recorded() {
+        let server = StubServer::start(|_| {
+            (
+                200,
+                page("You gave an answer too recently. You have 30s left to wait."),
+            )
+        });
+        let backend = HttpFetch::new(&server.url(), "secret");
+        let mut log = SubmissionLog::default();
+
+        assert_eq!(
+            Reply::Wait(30),
+            submit(&backend, &mut log, 2023, 5, 2, "7").unwrap()
+        );
+        assert_eq!(
+            Reply::Wait(30),
+            submit(&backend, &mut log, 2023, 5, 2, "7").unwrap()
+        );
+        assert!(log.submissions.is_empty());
+        assert_eq!(2, server.requests().len());
+    }
+}
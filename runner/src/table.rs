@@ -0,0 +1,128 @@
+use std::{fmt::Display, time::Duration};
+
+/// A plain text table with columns sized to fit their contents.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(headers: I) -> Self {
+        Self {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push<I: IntoIterator<Item = S>, S: Into<String>>(&mut self, row: I) {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut widths = self
+            .headers
+            .iter()
+            .map(|header| header.chars().count())
+            .collect::<Vec<_>>();
+        for row in self.rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in std::iter::once(&self.headers).chain(self.rows.iter()) {
+            let line = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a duration with a unit that keeps the number readable, for
+/// example `512.3µs` or `1.25s`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+/// Formats seconds since the Unix epoch as a UTC date and time, for example
+/// `2023-12-05 05:00:00`.
+pub fn format_timestamp(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let time = seconds % 86_400;
+
+    // days to a civil date, from Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_duration, format_timestamp, Table};
+    use std::time::Duration;
+
+    #[test]
+    fn columns_line_up() {
+        let mut table = Table::new(["Day", "Answer"]);
+        table.push(["1", "54953"]);
+        table.push(["12", ""]);
+        assert_eq!("Day  Answer\n1    54953\n12\n", table.to_string());
+    }
+
+    #[test]
+    fn duration_units() {
+        assert_eq!("999ns", format_duration(Duration::from_nanos(999)));
+        assert_eq!("1.5µs", format_duration(Duration::from_nanos(1_500)));
+        assert_eq!("12.35ms", format_duration(Duration::from_micros(12_345)));
+        assert_eq!("2.00s", format_duration(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!("1970-01-01 00:00:00", format_timestamp(0));
+        assert_eq!("2023-12-05 05:00:07", format_timestamp(1_701_752_407));
+        assert_eq!("2024-02-29 23:59:59", format_timestamp(1_709_251_199));
+    }
+}
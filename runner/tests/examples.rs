@@ -0,0 +1,14 @@
+//! One test per example under `examples/`, generated by the build script.
+
+use std::path::Path;
+
+use aoc::examples::Example;
+
+fn check_example(input_path: &str) {
+    let example = Example::load(Path::new(input_path)).unwrap_or_else(|err| panic!("{}", err));
+    if let Err(mismatches) = example.check() {
+        panic!("{}", mismatches);
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
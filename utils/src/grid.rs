@@ -0,0 +1,519 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use tokenizer::{Expected, ParseError, Span};
+
+/// A cell in a `Grid`. Rows count down from the top and columns count right
+/// from the left, both starting at 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Moves by the given number of rows and columns, returning `None` if
+    /// that would go above or left of the origin.
+    pub fn offset(self, rows: isize, cols: isize) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(rows)?,
+            col: self.col.checked_add_signed(cols)?,
+        })
+    }
+}
+
+const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// A horizontal run of cells in a single row, for example the digits of a
+/// number. `cols` is the half-open range of columns the run covers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Run {
+    pub row: usize,
+    pub cols: Span,
+}
+
+impl Run {
+    pub fn len(&self) -> usize {
+        self.cols.end() - self.cols.start()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let row = self.row;
+        (self.cols.start()..self.cols.end()).map(move |col| Position::new(row, col))
+    }
+}
+
+/// A rectangular grid of cells stored in row-major order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from puzzle text, converting each character with
+    /// `convert`. Blank lines at the start and end of the text are ignored,
+    /// and every other line must have the same number of characters.
+    pub fn parse<F: FnMut(char) -> T>(text: &str, mut convert: F) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (offset, line) in lines_with_offsets(text) {
+            let line_width = line.chars().count();
+            let expected_width = *width.get_or_insert(line_width);
+            if line_width != expected_width {
+                let start = match line.char_indices().nth(expected_width) {
+                    Some((i, _)) => offset + i,
+                    None => offset + line.len(),
+                };
+                let end = text[start..]
+                    .chars()
+                    .next()
+                    .map_or(start, |ch| start + ch.len_utf8());
+
+                return Err(ParseError::new(
+                    Expected::Described("a row as wide as the first row"),
+                    Span::new(start, end),
+                    text,
+                ));
+            }
+
+            cells.extend(line.chars().map(&mut convert));
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Like `parse`, but rows shorter than the longest row are padded on the
+    /// right with `fill` instead of being rejected.
+    pub fn parse_padded<F: FnMut(char) -> T>(text: &str, fill: T, mut convert: F) -> Self
+    where
+        T: Clone,
+    {
+        let lines = lines_with_offsets(text)
+            .map(|(_, line)| line)
+            .collect::<Vec<_>>();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in lines.iter() {
+            let start = cells.len();
+            cells.extend(line.chars().map(&mut convert));
+            cells.resize(start + width, fill.clone());
+        }
+
+        Self {
+            width,
+            height: lines.len(),
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.height && position.col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[position.row * self.width + position.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.row * self.width + position.col])
+        } else {
+            None
+        }
+    }
+
+    /// Every position in the grid in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Position::new(i / width, i % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The in-bounds cells directly above, right of, below and left of
+    /// `position`.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> {
+        self.neighbors(position, &NEIGHBORS_4)
+    }
+
+    /// The in-bounds cells surrounding `position`, including diagonals.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> {
+        self.neighbors(position, &NEIGHBORS_8)
+    }
+
+    fn neighbors(
+        &self,
+        position: Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> {
+        let (width, height) = (self.width, self.height);
+        offsets
+            .iter()
+            .filter_map(move |(rows, cols)| position.offset(*rows, *cols))
+            .filter(move |neighbor| neighbor.row < height && neighbor.col < width)
+    }
+
+    /// The in-bounds cells bordering `run`, including the diagonals at either
+    /// end.
+    pub fn around(&self, run: &Run) -> impl Iterator<Item = Position> {
+        let first_col = run.cols.start().saturating_sub(1);
+        let last_col = (run.cols.end() + 1).min(self.width);
+        let first_row = run.row.saturating_sub(1);
+        let last_row = (run.row + 2).min(self.height);
+        let run = *run;
+
+        (first_row..last_row)
+            .flat_map(move |row| (first_col..last_col).map(move |col| Position::new(row, col)))
+            .filter(move |position| {
+                position.row != run.row
+                    || position.col < run.cols.start()
+                    || position.col >= run.cols.end()
+            })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a chunk size of 0, and an empty grid has no rows
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// The diagonals running down and to the right, starting from the bottom
+    /// left corner and ending at the top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Vec<Position>> + '_ {
+        let starts = (0..self.height)
+            .rev()
+            .map(|row| Position::new(row, 0))
+            .chain((1..self.width).map(|col| Position::new(0, col)));
+
+        starts.map(move |start| self.walk(start, 1, 1))
+    }
+
+    /// The diagonals running down and to the left, starting from the top left
+    /// corner and ending at the bottom right corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Vec<Position>> + '_ {
+        let last_col = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|col| Position::new(0, col))
+            .chain((1..self.height).map(move |row| Position::new(row, last_col)));
+
+        starts.map(move |start| self.walk(start, 1, -1))
+    }
+
+    fn walk(&self, start: Position, rows: isize, cols: isize) -> Vec<Position> {
+        let mut positions = Vec::new();
+        let mut current = Some(start);
+        while let Some(position) = current.filter(|position| self.contains(*position)) {
+            positions.push(position);
+            current = position.offset(rows, cols);
+        }
+        positions
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, convert: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(convert).collect(),
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |row, col| Position::new(col, row))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearrange(self.height, self.width, move |row, col| {
+            Position::new(height - 1 - col, row)
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearrange(self.height, self.width, move |row, col| {
+            Position::new(col, width - 1 - row)
+        })
+    }
+
+    /// Builds a `width` by `height` grid where the cell at `(row, col)` is
+    /// copied from `source(row, col)` in this grid.
+    fn rearrange<F: Fn(usize, usize) -> Position>(
+        &self,
+        width: usize,
+        height: usize,
+        source: F,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| self[source(row, col)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Finds every maximal horizontal run of cells matching `predicate`.
+    pub fn runs<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Vec<Run> {
+        let mut runs = Vec::new();
+        for (row, cells) in self.rows().enumerate() {
+            let mut start = None;
+            for (col, cell) in cells.iter().enumerate() {
+                match (predicate(cell), start) {
+                    (true, None) => start = Some(col),
+                    (false, Some(run_start)) => {
+                        runs.push(Run {
+                            row,
+                            cols: Span::new(run_start, col),
+                        });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(run_start) = start {
+                runs.push(Run {
+                    row,
+                    cols: Span::new(run_start, self.width),
+                });
+            }
+        }
+
+        runs
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "position {:?} is outside of a {}x{} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!(
+                "position {:?} is outside of a {}x{} grid",
+                position, width, height
+            )
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The lines of `text` with their byte offsets, skipping blank lines at the
+/// start and end.
+fn lines_with_offsets(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut offset = 0;
+    let mut lines = text
+        .split('\n')
+        .map(|line| {
+            let start = offset;
+            offset += line.len() + 1;
+            (start, line.strip_suffix('\r').unwrap_or(line))
+        })
+        .collect::<Vec<_>>();
+
+    while lines.last().is_some_and(|(_, line)| line.is_empty()) {
+        lines.pop();
+    }
+    let first = lines
+        .iter()
+        .position(|(_, line)| !line.is_empty())
+        .unwrap_or(lines.len());
+
+    lines.into_iter().skip(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, Position, Run};
+    use tokenizer::Span;
+
+    const EXAMPLE: &str = "\n467..\n...*.\n..35.\n";
+
+    #[test]
+    fn parse_and_index() {
+        let grid = Grid::parse(EXAMPLE, |ch| ch).unwrap();
+        assert_eq!((5, 3), (grid.width(), grid.height()));
+        assert_eq!('*', grid[Position::new(1, 3)]);
+        assert_eq!(None, grid.get(Position::new(3, 0)));
+        assert_eq!("467..\n...*.\n..35.\n", grid.to_string());
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let err = Grid::parse("abc\nab\nabc", |ch| ch).unwrap_err();
+        assert_eq!(2, err.location().line);
+        assert_eq!(3, err.location().column);
+
+        let padded = Grid::parse_padded("abc\nab", '.', |ch| ch);
+        assert_eq!("abc\nab.\n", padded.to_string());
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            vec![Position::new(0, 1), Position::new(1, 0)],
+            grid.neighbors4(Position::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(3, grid.neighbors8(Position::new(2, 2)).count());
+        assert_eq!(8, grid.neighbors8(Position::new(1, 1)).count());
+    }
+
+    #[test]
+    fn runs_and_surroundings() {
+        let grid = Grid::parse(EXAMPLE, |ch| ch).unwrap();
+        let runs = grid.runs(|ch| ch.is_ascii_digit());
+        assert_eq!(
+            vec![
+                Run {
+                    row: 0,
+                    cols: Span::new(0, 3)
+                },
+                Run {
+                    row: 2,
+                    cols: Span::new(2, 4)
+                },
+            ],
+            runs
+        );
+
+        let around = grid.around(&runs[1]).collect::<Vec<_>>();
+        assert_eq!(6, around.len());
+        assert!(around.contains(&Position::new(1, 3)));
+        assert!(!around.contains(&Position::new(2, 2)));
+    }
+
+    #[test]
+    fn rotations_and_diagonals() {
+        let grid = Grid::parse("ab\ncd\nef", |ch| ch).unwrap();
+        assert_eq!("ace\nbdf\n", grid.transpose().to_string());
+        assert_eq!("eca\nfdb\n", grid.rotate_clockwise().to_string());
+        assert_eq!("bdf\nace\n", grid.rotate_counterclockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counterclockwise());
+
+        let diagonals = grid
+            .diagonals()
+            .map(|diagonal| diagonal.into_iter().map(|p| grid[p]).collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["e", "cf", "ad", "b"], diagonals);
+
+        let anti_diagonals = grid
+            .anti_diagonals()
+            .map(|diagonal| diagonal.into_iter().map(|p| grid[p]).collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["a", "bc", "de", "f"], anti_diagonals);
+
+        let columns = grid
+            .columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["ace", "bdf"], columns);
+    }
+}
@@ -0,0 +1,192 @@
+//! Loading personal puzzle inputs at runtime.
+//!
+//! Inputs are not committed, so they are read from
+//! `<input dir>/<year>/day<NN>.txt` when a solution runs instead of being
+//! compiled in. The input directory is `inputs/` at the root of the
+//! repository unless overridden with the `AOC_INPUT_DIR` environment variable
+//! or the runner's `--input-dir <dir>` flag.
+//!
+//! More inputs for the same day, such as teammates', can be kept as
+//! `<input dir>/<year>/day<NN>/<name>.txt`. The runner's `verify` command
+//! checks all of them.
+
+use std::{
+    error::Error,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { path: PathBuf, year: u16, day: u8 },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { path, year, day } => write!(
+                f,
+                "no puzzle input found at {}. Download it from https://adventofcode.com/{}/day/{}/input and save it there, or set {} to the directory holding your inputs.",
+                path.display(),
+                year,
+                day,
+                INPUT_DIR_VAR
+            ),
+            Self::Io { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Missing { .. } => None,
+            Self::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+/// The directory inputs are read from when no flag is given: `AOC_INPUT_DIR`
+/// if it is set, otherwise `inputs/` at the root of the repository.
+pub fn default_input_dir() -> PathBuf {
+    match std::env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("utils to live inside the workspace")
+            .join("inputs"),
+    }
+}
+
+pub fn input_path(input_dir: &Path, year: u16, day: u8) -> PathBuf {
+    input_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.txt", day))
+}
+
+/// A puzzle input on disk, named so it can be told apart from other inputs
+/// for the same day.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Every input for `day`: `day<NN>.txt`, named `default`, followed by each
+/// `day<NN>/<name>.txt` in name order.
+pub fn find_inputs(input_dir: &Path, year: u16, day: u8) -> io::Result<Vec<NamedInput>> {
+    let mut inputs = Vec::new();
+
+    let path = input_path(input_dir, year, day);
+    if path.is_file() {
+        inputs.push(NamedInput {
+            name: "default".to_string(),
+            path,
+        });
+    }
+
+    let dir = input_dir
+        .join(year.to_string())
+        .join(format!("day{:02}", day));
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(inputs),
+        Err(err) => return Err(err),
+    };
+
+    let mut named = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                named.push(NamedInput {
+                    name: name.to_string(),
+                    path: path.clone(),
+                });
+            }
+        }
+    }
+    named.sort_by(|a, b| a.name.cmp(&b.name));
+    inputs.extend(named);
+
+    Ok(inputs)
+}
+
+pub fn load_from(input_dir: &Path, year: u16, day: u8) -> Result<String, InputError> {
+    let path = input_path(input_dir, year, day);
+    std::fs::read_to_string(&path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing { path, year, day },
+        _ => InputError::Io { path, source },
+    })
+}
+
+pub fn load(year: u16, day: u8) -> Result<String, InputError> {
+    load_from(&default_input_dir(), year, day)
+}
+
+/// Evaluates to the puzzle input for `year` and `day`, for tests that need
+/// one. Inputs are personal, so such tests should be marked `#[ignore]` and
+/// are run with `cargo test -- --ignored` once the input is downloaded. A
+/// missing input then fails the test rather than letting it pass.
+///
+/// ```no_run
+/// #[test]
+/// #[ignore = "needs the puzzle input"]
+/// fn actual() {
+///     let data = utils::require_input!(2023, 1);
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! require_input {
+    ($year:expr, $day:expr) => {
+        $crate::input::load($year, $day).unwrap_or_else(|err| panic!("{}", err))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_inputs, input_path, load_from, InputError};
+    use std::{fs, path::Path};
+
+    #[test]
+    fn path_layout() {
+        assert_eq!(
+            Path::new("inputs/2023/day05.txt"),
+            input_path(Path::new("inputs"), 2023, 5)
+        );
+    }
+
+    #[test]
+    fn missing_input_is_reported() {
+        let err = load_from(Path::new("/nonexistent"), 2023, 5).unwrap_err();
+        assert!(matches!(err, InputError::Missing { .. }));
+        assert!(err.to_string().contains("/nonexistent/2023/day05.txt"));
+    }
+
+    #[test]
+    fn default_and_named_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-find-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2023/day05")).unwrap();
+        fs::write(dir.join("2023/day05.txt"), "mine").unwrap();
+        fs::write(dir.join("2023/day05/bob.txt"), "bob's").unwrap();
+        fs::write(dir.join("2023/day05/alice.txt"), "alice's").unwrap();
+        fs::write(dir.join("2023/day05/notes.md"), "").unwrap();
+
+        let names = find_inputs(&dir, 2023, 5)
+            .unwrap()
+            .into_iter()
+            .map(|input| input.name)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["default", "alice", "bob"], names);
+        assert!(find_inputs(&dir, 2023, 6).unwrap().is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,512 @@
+//! Sets of integers stored as ranges, and maps that shift ranges of integers
+//! by an offset, for puzzles whose inputs describe far more numbers than can
+//! be visited one at a time.
+//!
+//! Every range is half-open, `start..end`, as with `std::ops::Range`.
+
+use std::{fmt::Display, ops::Range};
+
+/// A set of integers, kept as sorted, disjoint, non-adjacent and non-empty
+/// ranges.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every integer in `range`.
+    pub fn insert(&mut self, range: Range<i64>) {
+        *self = self.union(&Self::from_iter([range]));
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        // the first range that ends after `value` is the only one that can
+        // hold it
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.start.abs_diff(range.end))
+            .sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|range| range.end - 1)
+    }
+
+    /// The ranges, in order.
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // whichever ends first can't overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Every integer in `self` that isn't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+        for range in self.ranges.iter() {
+            let mut start = range.start;
+            while let Some(cut) = others.peek() {
+                if cut.end <= start {
+                    others.next();
+                    continue;
+                }
+                if cut.start >= range.end {
+                    break;
+                }
+
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+                if cut.end > range.end {
+                    // may still cut into the next range
+                    break;
+                }
+                others.next();
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    /// Collects ranges in any order, overlapping or not. Empty ranges are
+    /// ignored.
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(ranges: I) -> Self {
+        let mut sorted = ranges
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect::<Vec<_>>();
+        sorted.sort_by_key(|range| range.start);
+
+        let mut ranges: Vec<Range<i64>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}..{}", range.start, range.end)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// A piecewise map from integers to integers: each of a set of disjoint
+/// source ranges is shifted by its own offset, and every other integer maps to
+/// itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// Sorted by start, disjoint and non-empty.
+    entries: Vec<(Range<i64>, i64)>,
+}
+
+/// Two source ranges of a `RangeMap` that share integers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overlap {
+    pub existing: Range<i64>,
+    pub new: Range<i64>,
+}
+
+impl Display for Overlap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}..{} overlaps {}..{}",
+            self.new.start, self.new.end, self.existing.start, self.existing.end
+        )
+    }
+}
+
+impl std::error::Error for Overlap {}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps every integer in `source` to itself plus `offset`. Fails if
+    /// `source` overlaps a range that is already mapped. Empty ranges are
+    /// ignored.
+    pub fn insert(&mut self, source: Range<i64>, offset: i64) -> Result<(), Overlap> {
+        if source.is_empty() {
+            return Ok(());
+        }
+
+        let index = self
+            .entries
+            .partition_point(|(range, _)| range.start < source.start);
+        let neighbours = [index.checked_sub(1), Some(index)];
+        for (existing, _) in neighbours
+            .into_iter()
+            .flatten()
+            .filter_map(|i| self.entries.get(i))
+        {
+            if existing.start < source.end && source.start < existing.end {
+                return Err(Overlap {
+                    existing: existing.clone(),
+                    new: source,
+                });
+            }
+        }
+
+        self.entries.insert(index, (source, offset));
+        Ok(())
+    }
+
+    /// The mapped ranges and their offsets, in order.
+    pub fn entries(&self) -> &[(Range<i64>, i64)] {
+        &self.entries
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        let index = self
+            .entries
+            .partition_point(|(range, _)| range.end <= value);
+        match self.entries.get(index) {
+            Some((range, offset)) if range.start <= value => value + offset,
+            _ => value,
+        }
+    }
+
+    /// The image of every integer in `set`.
+    pub fn apply(&self, set: &IntervalSet) -> IntervalSet {
+        set.ranges()
+            .iter()
+            .flat_map(|range| self.split(range.clone()))
+            .map(|(piece, offset)| piece.start + offset..piece.end + offset)
+            .collect()
+    }
+
+    /// The map that sends each integer through `self` and then through
+    /// `next`, as a single lookup.
+    ///
+    /// Only integers below `i64::MAX` are covered; that one is left as it is.
+    pub fn then(&self, next: &Self) -> Self {
+        let mut entries: Vec<(Range<i64>, i64)> = Vec::new();
+        for (piece, offset) in self.split(i64::MIN..i64::MAX) {
+            let image = piece.start + offset..piece.end + offset;
+            for (part, next_offset) in next.split(image) {
+                let source = part.start - offset..part.end - offset;
+                let total = offset + next_offset;
+                if total == 0 {
+                    continue;
+                }
+
+                match entries.last_mut() {
+                    Some((last, last_offset))
+                        if last.end == source.start && *last_offset == total =>
+                    {
+                        last.end = source.end
+                    }
+                    _ => entries.push((source, total)),
+                }
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// The map that undoes this one, or `None` if two integers map to the
+    /// same one.
+    pub fn inverse(&self) -> Option<Self> {
+        let mut inverse = Self::new();
+        for (range, offset) in self.entries.iter() {
+            // an overlap means two entries share an image
+            inverse
+                .insert(range.start + offset..range.end + offset, -offset)
+                .ok()?;
+        }
+
+        // the images are disjoint, so what's left is a bijection exactly when
+        // the entries only move integers around among themselves
+        let sources = self.entries.iter().map(|(range, _)| range.clone());
+        let images = inverse.entries.iter().map(|(range, _)| range.clone());
+        (IntervalSet::from_iter(sources) == IntervalSet::from_iter(images)).then_some(inverse)
+    }
+
+    /// Cuts `range` where entries start and end, pairing each piece with the
+    /// offset it's shifted by, in order.
+    fn split(&self, range: Range<i64>) -> Vec<(Range<i64>, i64)> {
+        let mut pieces = Vec::new();
+        let mut start = range.start;
+        let first = self
+            .entries
+            .partition_point(|(entry, _)| entry.end <= start);
+        for (entry, offset) in self.entries[first..].iter() {
+            if entry.start >= range.end {
+                break;
+            }
+            if start < entry.start {
+                pieces.push((start..entry.start, 0));
+                start = entry.start;
+            }
+
+            let end = range.end.min(entry.end);
+            pieces.push((start..end, *offset));
+            start = end;
+        }
+
+        if start < range.end {
+            pieces.push((start..range.end, 0));
+        }
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntervalSet, Overlap, RangeMap};
+    use proptest::prelude::*;
+    use std::{collections::BTreeSet, ops::Range};
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet {
+        ranges.iter().map(|&(start, end)| start..end).collect()
+    }
+
+    fn elements(set: &IntervalSet) -> BTreeSet<i64> {
+        set.ranges().iter().cloned().flatten().collect()
+    }
+
+    #[test]
+    fn normalises() {
+        let merged = set(&[(5, 7), (1, 3), (2, 4), (4, 5), (10, 10), (12, 15)]);
+        assert_eq!(&[1..7, 12..15], merged.ranges());
+        assert_eq!("{1..7, 12..15}", merged.to_string());
+        assert_eq!(9, merged.len());
+        assert_eq!((Some(1), Some(14)), (merged.min(), merged.max()));
+        assert!(merged.contains(6) && !merged.contains(7) && !merged.contains(0));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(set(&[(0, 30)]), a.union(&b));
+        assert_eq!(set(&[(5, 10), (20, 25)]), a.intersection(&b));
+        assert_eq!(set(&[(0, 5), (25, 30)]), a.difference(&b));
+        assert_eq!(set(&[(10, 20)]), b.difference(&a));
+    }
+
+    #[test]
+    fn map_rejects_overlaps() {
+        let mut map = RangeMap::new();
+        map.insert(10..20, 5).unwrap();
+        map.insert(0..10, 1).unwrap();
+        assert_eq!(
+            Err(Overlap {
+                existing: 10..20,
+                new: 19..30
+            }),
+            map.insert(19..30, 0)
+        );
+        assert!(map.insert(5..15, 0).is_err());
+        assert_eq!(Ok(()), map.insert(20..30, -20));
+        assert_eq!(None, map.inverse());
+        assert_eq!(
+            vec![1, 10, 15, 0, 40],
+            [0, 9, 10, 20, 40].map(|x| map.get(x)).to_vec()
+        );
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<Range<i64>>> {
+        prop::collection::vec(
+            (0i64..40, 0i64..10).prop_map(|(start, len)| start..start + len),
+            0..6,
+        )
+    }
+
+    fn map() -> impl Strategy<Value = RangeMap> {
+        prop::collection::vec((0i64..40, 0i64..10, -20i64..20), 0..6).prop_map(|entries| {
+            let mut map = RangeMap::new();
+            for (start, len, offset) in entries {
+                // overlapping entries are rejected, which leaves a smaller map
+                let _ = map.insert(start..start + len, offset);
+            }
+            map
+        })
+    }
+
+    /// Moves blocks of integers starting at `start` around among themselves.
+    fn bijection() -> impl Strategy<Value = RangeMap> {
+        (0i64..20, prop::collection::vec(1i64..8, 1..6))
+            .prop_flat_map(|(start, lens)| {
+                let order = Just((0..lens.len()).collect::<Vec<_>>()).prop_shuffle();
+                (Just(start), Just(lens), order)
+            })
+            .prop_map(|(start, lens, order)| {
+                let starts = |order: &mut dyn Iterator<Item = usize>| {
+                    let mut starts = vec![0; lens.len()];
+                    let mut next = start;
+                    for i in order {
+                        starts[i] = next;
+                        next += lens[i];
+                    }
+                    starts
+                };
+                let sources = starts(&mut (0..lens.len()));
+                let destinations = starts(&mut order.into_iter());
+
+                let mut map = RangeMap::new();
+                for i in 0..lens.len() {
+                    let offset = destinations[i] - sources[i];
+                    map.insert(sources[i]..sources[i] + lens[i], offset)
+                        .unwrap();
+                }
+                map
+            })
+    }
+
+    #[test]
+    fn composes_and_inverts() {
+        let mut first = RangeMap::new();
+        first.insert(0..10, 10).unwrap();
+        first.insert(10..20, -10).unwrap();
+        let mut second = RangeMap::new();
+        second.insert(5..15, 100).unwrap();
+
+        let both = first.then(&second);
+        assert_eq!(
+            &[(0..5, 110), (5..10, 10), (10..15, -10), (15..20, 90)],
+            both.entries()
+        );
+        assert_eq!(first, first.inverse().unwrap());
+        // the composition isn't a bijection, since 105..115 has two preimages
+        assert_eq!(None, both.inverse());
+        assert_eq!(first, RangeMap::new().then(&first));
+        assert_eq!(RangeMap::new(), first.then(&first));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force_sets(a in ranges(), b in ranges()) {
+            let (a, b) = (IntervalSet::from_iter(a), IntervalSet::from_iter(b));
+            let (ea, eb) = (elements(&a), elements(&b));
+
+            prop_assert_eq!(ea.union(&eb).cloned().collect::<BTreeSet<_>>(), elements(&a.union(&b)));
+            prop_assert_eq!(ea.intersection(&eb).cloned().collect::<BTreeSet<_>>(), elements(&a.intersection(&b)));
+            prop_assert_eq!(ea.difference(&eb).cloned().collect::<BTreeSet<_>>(), elements(&a.difference(&b)));
+            for value in -1..60 {
+                prop_assert_eq!(ea.contains(&value), a.contains(value));
+            }
+
+            // results are normalised, so equal sets compare equal
+            for result in [a.union(&b), a.intersection(&b), a.difference(&b)] {
+                prop_assert_eq!(&result, &result.ranges().iter().cloned().collect::<IntervalSet>());
+            }
+        }
+
+        #[test]
+        fn matches_brute_force_mapping(seeds in ranges(), map in map()) {
+            let seeds = IntervalSet::from_iter(seeds);
+            let expected = elements(&seeds)
+                .into_iter()
+                .map(|seed| map.get(seed))
+                .collect::<BTreeSet<_>>();
+            prop_assert_eq!(expected, elements(&map.apply(&seeds)));
+
+            for value in -1..60 {
+                let entry = map.entries().iter().find(|(range, _)| range.contains(&value));
+                prop_assert_eq!(value + entry.map_or(0, |(_, offset)| *offset), map.get(value));
+            }
+        }
+
+        #[test]
+        fn composition_matches_applying_in_turn(first in map(), second in map(), seeds in ranges()) {
+            let both = first.then(&second);
+            for value in -30..80 {
+                prop_assert_eq!(second.get(first.get(value)), both.get(value));
+            }
+
+            let seeds = IntervalSet::from_iter(seeds);
+            prop_assert_eq!(second.apply(&first.apply(&seeds)), both.apply(&seeds));
+        }
+
+        #[test]
+        fn inverse_undoes_bijections(map in bijection(), other in bijection()) {
+            let inverse = map.inverse().unwrap();
+            let both = map.then(&other);
+            let both_inverse = both.inverse().unwrap();
+            for value in -10..80 {
+                prop_assert_eq!(value, inverse.get(map.get(value)));
+                prop_assert_eq!(value, both_inverse.get(both.get(value)));
+            }
+        }
+
+        #[test]
+        fn inverse_exists_exactly_for_bijections(map in map()) {
+            // every entry lies in 0..50 and moves at most 20 either way, so
+            // any collision is in this window
+            let images = (-30..80).map(|value| map.get(value)).collect::<BTreeSet<_>>();
+            let injective = images.len() == 110;
+            prop_assert_eq!(injective, map.inverse().is_some());
+            if let Some(inverse) = map.inverse() {
+                for value in -30..80 {
+                    prop_assert_eq!(value, inverse.get(map.get(value)));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Helpers shared between the solutions that aren't about parsing text. Those
+//! live in `tokenizer`.
+
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod solution;
+
+pub use grid::{Grid, Position, Run};
+pub use interval::{IntervalSet, RangeMap};
+pub use solution::Solution;
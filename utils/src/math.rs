@@ -0,0 +1,327 @@
+//! Number theory that keeps coming up in puzzles: cycles that line up (lcm,
+//! the Chinese remainder theorem), modular arithmetic and primality.
+//!
+//! Intermediate products are computed in 128 bits, so none of these overflow
+//! along the way. Where a result may not fit the return type, the function
+//! says so.
+
+use std::fmt::Display;
+
+/// Greatest common divisor, by Euclid's algorithm. `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple. Panics if the result doesn't fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .unwrap_or_else(|| panic!("lcm({}, {}) overflows u64", a, b))
+}
+
+/// The least common multiple of every number, or 1 if there are none.
+pub fn lcm_all<I: IntoIterator<Item = u64>>(nums: I) -> u64 {
+    nums.into_iter().fold(1, lcm)
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g`, where `g` is the
+/// greatest common divisor of `a` and `b`, and is never negative.
+///
+/// Panics if the gcd is 2^63, which doesn't fit in an `i64`. That only
+/// happens when one argument is `i64::MIN` and the other is 0 or `i64::MIN`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_wide(a as i128, b as i128);
+    let g = i64::try_from(g)
+        .unwrap_or_else(|_| panic!("gcd({}, {}) is 2^63, which overflows i64", a, b));
+    (g, x as i64, y as i64)
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` and
+/// `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "modulus must be positive, not {}", modulus);
+    let (g, x, _) = extended_gcd_wide(a as i128, modulus as i128);
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as i64)
+}
+
+/// Why `crt` has no answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrtError {
+    /// The congruences contradict each other.
+    NoSolution,
+    /// The least common multiple of the moduli doesn't fit in an `i64`.
+    Overflow,
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSolution => write!(f, "the congruences have no common solution"),
+            Self::Overflow => write!(f, "the combined modulus overflows i64"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Solves the system `x ≡ residue (mod modulus)` for every
+/// `(residue, modulus)`, by the Chinese remainder theorem. The moduli don't
+/// have to be coprime. Returns the smallest non-negative solution along with
+/// the least common multiple of the moduli, which every other solution differs
+/// from it by a multiple of. No congruences at all is solved by `(0, 1)`.
+///
+/// Panics if a modulus isn't positive.
+pub fn crt(congruences: &[(i64, i64)]) -> Result<(i64, i64), CrtError> {
+    let mut x = 0i128;
+    let mut modulus = 1i128;
+    for &(residue, m) in congruences {
+        assert!(m > 0, "modulus must be positive, not {}", m);
+        let (residue, m) = ((residue as i128).rem_euclid(m as i128), m as i128);
+
+        // x + modulus * k ≡ residue (mod m) has a solution for k exactly
+        // when the gcd divides the difference
+        let (g, inverse, _) = extended_gcd_wide(modulus, m);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return Err(CrtError::NoSolution);
+        }
+
+        let step = m / g;
+        let k = (difference / g % step * inverse).rem_euclid(step);
+        x += modulus * k;
+        modulus *= step;
+        if modulus > i64::MAX as i128 {
+            return Err(CrtError::Overflow);
+        }
+        x = x.rem_euclid(modulus);
+    }
+
+    Ok((x as i64, modulus as i64))
+}
+
+/// The largest `r` with `r * r <= n`.
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // the float estimate is off by at most one either way for any u64
+    let mut root = (n as f64).sqrt() as u64;
+    while root.checked_mul(root).is_none_or(|square| square > n) {
+        root -= 1;
+    }
+    while (root + 1)
+        .checked_mul(root + 1)
+        .is_some_and(|square| square <= n)
+    {
+        root += 1;
+    }
+    root
+}
+
+/// `base` to the power `exp`, modulo `modulus`, by repeated squaring.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "modulus must be positive");
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Whether `n` is prime, by a Miller–Rabin test. The bases used make it
+/// exact for every `u64`.
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for base in BASES {
+        if n.is_multiple_of(base) {
+            return n == base;
+        }
+    }
+
+    // n - 1 = d * 2^s with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    BASES.iter().all(|&base| {
+        let mut x = mod_pow(base, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = (x as u128 * x as u128 % n as u128) as u64;
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        crt, extended_gcd, gcd, is_prime, isqrt, lcm, lcm_all, mod_inverse, mod_pow, CrtError,
+    };
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(6, gcd(48, 18));
+        assert_eq!(7, gcd(0, 7));
+        assert_eq!(0, gcd(0, 0));
+        assert_eq!(36, lcm(12, 18));
+        assert_eq!(0, lcm(0, 5));
+        assert_eq!(2520, lcm_all(1..=10));
+        assert_eq!(1, lcm_all([]));
+    }
+
+    #[test]
+    fn bezout_coefficients() {
+        let extremes = [i64::MIN, i64::MIN + 1, i64::MAX, 1 << 62, 3 << 61];
+        let small = -30..30;
+        for a in small.clone().chain(extremes) {
+            for b in small.clone().chain(extremes) {
+                if a == i64::MIN && (b == 0 || b == i64::MIN)
+                    || b == i64::MIN && (a == 0 || a == i64::MIN)
+                {
+                    continue;
+                }
+
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(gcd(a.unsigned_abs(), b.unsigned_abs()), g as u64);
+                assert_eq!(
+                    g as i128,
+                    a as i128 * x as i128 + b as i128 * y as i128,
+                    "a = {}, b = {}",
+                    a,
+                    b
+                );
+            }
+        }
+
+        for (a, b) in [(i64::MIN, 0), (0, i64::MIN), (i64::MIN, i64::MIN)] {
+            assert!(std::panic::catch_unwind(|| extended_gcd(a, b)).is_err());
+        }
+    }
+
+    #[test]
+    fn inverses() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(6, 9));
+        for m in 1..50 {
+            for a in 0..m {
+                match mod_inverse(a, m) {
+                    Some(x) => assert_eq!(1 % m, a * x % m),
+                    None => assert_ne!(1, gcd(a as u64, m as u64)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(Ok((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        // not coprime, but consistent
+        assert_eq!(Ok((10, 12)), crt(&[(2, 4), (4, 6)]));
+        assert_eq!(Err(CrtError::NoSolution), crt(&[(1, 4), (2, 6)]));
+        assert_eq!(Ok((0, 1)), crt(&[]));
+        assert_eq!(Ok((4, 7)), crt(&[(-3, 7)]));
+
+        // against brute force
+        for m1 in 1..13 {
+            for m2 in 1..13 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let expected = (0..m1 * m2)
+                            .find(|x| x % m1 == r1 && x % m2 == r2)
+                            .map(|x| (x, lcm(m1 as u64, m2 as u64) as i64))
+                            .ok_or(CrtError::NoSolution);
+                        assert_eq!(expected, crt(&[(r1, m1), (r2, m2)]));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_crt_does_not_overflow() {
+        let big = 1_000_000_007;
+        let bigger = 998_244_353;
+        let (x, m) = crt(&[(big - 1, big), (bigger - 1, bigger)]).unwrap();
+        assert_eq!(big * bigger, m);
+        assert_eq!(m - 1, x);
+
+        let too_big = [(1, big), (2, bigger), (3, 1_000_000_009)];
+        assert_eq!(Err(CrtError::Overflow), crt(&too_big));
+    }
+
+    #[test]
+    fn integer_square_roots() {
+        for n in 0..10_000 {
+            let root = isqrt(n);
+            assert!(root * root <= n && (root + 1) * (root + 1) > n);
+        }
+        assert_eq!(4_294_967_295, isqrt(u64::MAX));
+        assert_eq!(3_037_000_499, isqrt(9_223_372_036_854_775_807));
+        assert_eq!(1_000_000_000, isqrt(1_000_000_000_000_000_000));
+        assert_eq!(999_999_999, isqrt(999_999_999_999_999_999));
+    }
+
+    #[test]
+    fn modular_powers() {
+        assert_eq!(24, mod_pow(2, 10, 1000));
+        assert_eq!(0, mod_pow(5, 3, 1));
+        assert_eq!(1, mod_pow(0, 0, 7));
+        assert_eq!(1, mod_pow(u64::MAX - 1, u64::MAX - 1, u64::MAX));
+    }
+
+    #[test]
+    fn primes() {
+        let sieve = (0..10_000u64)
+            .map(|n| n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+            .collect::<Vec<_>>();
+        for (n, expected) in sieve.into_iter().enumerate() {
+            assert_eq!(expected, is_prime(n as u64), "{}", n);
+        }
+
+        assert!(is_prime(1_000_000_007));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        // strong pseudoprime to bases 2, 3, 5 and 7
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(1_000_000_007 * 998_244_353));
+    }
+}
@@ -0,0 +1,42 @@
+//! The shape every day's solution shares.
+
+use std::fmt::Display;
+
+/// A day's puzzle solution. The puzzle input is parsed once into `Input`, and
+/// both parts then work from that.
+///
+/// ```
+/// use utils::Solution;
+///
+/// struct Sum;
+///
+/// impl Solution for Sum {
+///     type Input = Vec<usize>;
+///
+///     fn parse(data: &str) -> Self::Input {
+///         data.lines().map(|line| line.parse().unwrap()).collect()
+///     }
+///
+///     fn part1(input: &Self::Input) -> impl std::fmt::Display {
+///         input.iter().sum::<usize>()
+///     }
+///
+///     fn part2(input: &Self::Input) -> impl std::fmt::Display {
+///         input.iter().max().copied().unwrap_or(0)
+///     }
+/// }
+///
+/// let input = Sum::parse("1\n2\n3\n");
+/// assert_eq!("6", Sum::part1(&input).to_string());
+/// ```
+pub trait Solution {
+    type Input;
+
+    /// Parses the puzzle input. Panics with a description of the problem if it
+    /// is malformed.
+    fn parse(data: &str) -> Self::Input;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    fn part2(input: &Self::Input) -> impl Display;
+}